@@ -35,6 +35,105 @@ fn criterion_benchmark(c: &mut Criterion) {
 
 	wyrand_group.finish();
 
+	let mut range_group = c.benchmark_group("generate_range");
+
+	range_group.bench_function("u64", |b| {
+		let mut rng = nanorand::rand::WyRand::new();
+		b.iter(|| {
+			black_box(rng.generate_range(black_box(1_u64..1_000_000_007)));
+		})
+	});
+
+	range_group.bench_function("u128 (small range)", |b| {
+		let mut rng = nanorand::rand::WyRand::new();
+		b.iter(|| {
+			black_box(rng.generate_range(black_box(1_u128..1_000_000_007)));
+		})
+	});
+
+	range_group.bench_function("u128 (large range)", |b| {
+		let mut rng = nanorand::rand::WyRand::new();
+		b.iter(|| {
+			black_box(rng.generate_range(black_box(1_u128..(1 << 100) + 7)));
+		})
+	});
+
+	range_group.bench_function("i128", |b| {
+		let mut rng = nanorand::rand::WyRand::new();
+		b.iter(|| {
+			black_box(rng.generate_range(black_box(-(1_i128 << 100)..(1 << 100))));
+		})
+	});
+
+	range_group.finish();
+
+	let mut weighted_group = c.benchmark_group("weighted");
+	let weights: Vec<u64> = (1..=1000).collect();
+
+	weighted_group.bench_function("linear scan", |b| {
+		let mut rng = nanorand::rand::WyRand::new();
+		let total: u64 = weights.iter().sum();
+		b.iter(|| {
+			let mut remaining = rng.generate_range(0..total);
+			let index = weights
+				.iter()
+				.position(|&weight| {
+					if remaining < weight {
+						true
+					} else {
+						remaining -= weight;
+						false
+					}
+				})
+				.unwrap();
+			black_box(index);
+		})
+	});
+
+	weighted_group.bench_function("alias table", |b| {
+		let mut rng = nanorand::rand::WyRand::new();
+		let table = nanorand::distributions::WeightedIndex::new(&weights).unwrap();
+		b.iter(|| {
+			black_box(rng.sample(&table));
+		})
+	});
+
+	weighted_group.bench_function("fenwick tree", |b| {
+		let mut rng = nanorand::rand::WyRand::new();
+		let tree = nanorand::distributions::DynamicWeightedIndex::new(&weights).unwrap();
+		b.iter(|| {
+			black_box(rng.sample(&tree));
+		})
+	});
+
+	weighted_group.finish();
+
+	let mut shuffle_group = c.benchmark_group("shuffle");
+	for length in [64, 10_000, 1_000_000] {
+		let mut items: Vec<u32> = (0..length).collect();
+		shuffle_group.throughput(Throughput::Elements(u64::from(length)));
+
+		shuffle_group.bench_function(format!("one index per swap ({})", length), |b| {
+			let mut rng = nanorand::rand::WyRand::new();
+			b.iter(|| {
+				for idx in (1..items.len()).rev() {
+					let random_idx = rng.generate_range(0..idx + 1);
+					items.swap(idx, random_idx);
+				}
+				black_box(&items);
+			})
+		});
+
+		shuffle_group.bench_function(format!("batched ({})", length), |b| {
+			let mut rng = nanorand::rand::WyRand::new();
+			b.iter(|| {
+				rng.shuffle(&mut items);
+				black_box(&items);
+			})
+		});
+	}
+	shuffle_group.finish();
+
 	let mut chacha_group = c.benchmark_group("ChaCha");
 	// ChaCha has 512-bit output
 	chacha_group.throughput(Throughput::Bytes(64));
@@ -89,6 +188,47 @@ fn criterion_benchmark(c: &mut Criterion) {
 
 	chacha_group.finish();
 
+	let mut chacha_fill_group = c.benchmark_group("ChaCha fill");
+	chacha_fill_group.throughput(Throughput::Bytes(4096));
+
+	chacha_fill_group.bench_function("nanorand<8>", |b| {
+		let mut rng = nanorand::rand::ChaCha8::new();
+		let mut out = [0u8; 4096];
+		b.iter(|| {
+			rng.fill_bytes(&mut out);
+			black_box(&out);
+		})
+	});
+
+	chacha_fill_group.bench_function("nanorand<20>", |b| {
+		let mut rng = nanorand::rand::ChaCha20::new();
+		let mut out = [0u8; 4096];
+		b.iter(|| {
+			rng.fill_bytes(&mut out);
+			black_box(&out);
+		})
+	});
+
+	chacha_fill_group.bench_function("rand_chacha<8>", |b| {
+		let mut rng = rand_chacha::ChaCha8Rng::from_seed([42; 32]);
+		let mut out = [0u8; 4096];
+		b.iter(|| {
+			RngCore::fill_bytes(&mut rng, &mut out);
+			black_box(&out);
+		})
+	});
+
+	chacha_fill_group.bench_function("rand_chacha<20>", |b| {
+		let mut rng = rand_chacha::ChaCha20Rng::from_seed([42; 32]);
+		let mut out = [0u8; 4096];
+		b.iter(|| {
+			RngCore::fill_bytes(&mut rng, &mut out);
+			black_box(&out);
+		})
+	});
+
+	chacha_fill_group.finish();
+
 	let mut pcg32_group = c.benchmark_group("Pcg32");
 	pcg32_group.throughput(Throughput::Bytes(4));
 
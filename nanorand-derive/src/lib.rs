@@ -0,0 +1,218 @@
+#![forbid(missing_docs)]
+#![warn(
+	clippy::perf,
+	clippy::complexity,
+	clippy::style,
+	clippy::correctness,
+	clippy::missing_const_for_fn
+)]
+
+//! Derive macros for [nanorand](https://crates.io/crates/nanorand).
+//!
+//! You probably want to use these through nanorand's `derive` feature, rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+	parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, Lit, Meta,
+	Result,
+};
+
+/// Derives `RandomGen` for a struct or enum.
+///
+/// Structs are generated by generating each of their fields.
+/// Enums pick one of their variants uniformly, and then generate that variant's fields.
+///
+/// # Attributes
+/// * `#[range(lower..upper)]` on a field generates it with `Rng::generate_range` instead,
+///   so the field's type must implement `RandomRange`. Any range expression works here.
+/// * `#[weight = N]` on an enum variant makes it be picked with a probability of `N` divided by
+///   the sum of the weights of all variants. Variants without a weight have a weight of 1,
+///   and variants with a weight of 0 are never picked.
+///
+/// ```rust
+/// use nanorand::{RandomGen, Rng, WyRand};
+///
+/// #[derive(RandomGen)]
+/// enum Rarity {
+///     #[weight = 90]
+///     Common,
+///     #[weight = 9]
+///     Rare,
+///     #[weight = 1]
+///     Legendary,
+/// }
+///
+/// #[derive(RandomGen)]
+/// struct Item {
+///     id: u64,
+///     rarity: Rarity,
+///     #[range(1..=100)]
+///     level: u8,
+/// }
+///
+/// let mut rng = WyRand::new();
+/// let item: Item = rng.generate();
+/// assert!((1..=100).contains(&item.level));
+/// ```
+#[proc_macro_derive(RandomGen, attributes(range, weight))]
+pub fn derive_random_gen(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	match expand(input) {
+		Ok(tokens) => tokens.into(),
+		Err(error) => error.to_compile_error().into(),
+	}
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+	let name = &input.ident;
+	let mut bounds = Vec::new();
+	let body = match &input.data {
+		Data::Struct(data) => {
+			let construct = construct(quote!(#name), &data.fields, &mut bounds)?;
+			quote!(#construct)
+		}
+		Data::Enum(data) => {
+			let mut arms = Vec::with_capacity(data.variants.len());
+			let mut total: u64 = 0;
+			for variant in &data.variants {
+				let weight = weight(&variant.attrs)?;
+				let variant_name = &variant.ident;
+				let construct =
+					construct(quote!(#name::#variant_name), &variant.fields, &mut bounds)?;
+				if weight == 0 {
+					continue;
+				}
+				let start = total;
+				total = total.checked_add(weight).ok_or_else(|| {
+					Error::new(
+						variant.span(),
+						"the total weight of all variants must fit in a u64",
+					)
+				})?;
+				let end = total - 1;
+				arms.push(quote!(#start..=#end => #construct,));
+			}
+			if total == 0 {
+				return Err(Error::new(
+					Span::call_site(),
+					"RandomGen can only be derived for enums with at least one variant that can be picked",
+				));
+			}
+			quote! {
+				match ::nanorand::Rng::generate_range(rng, 0_u64..#total) {
+					#(#arms)*
+					_ => unreachable!(),
+				}
+			}
+		}
+		Data::Union(_) => {
+			return Err(Error::new(
+				Span::call_site(),
+				"RandomGen can't be derived for unions",
+			))
+		}
+	};
+
+	let (_, type_generics, where_clause) = input.generics.split_for_impl();
+	let mut generics = input.generics.clone();
+	generics
+		.params
+		.push(syn::parse_quote!(__Generator: ::nanorand::Rng<__OUTPUT>));
+	generics
+		.params
+		.push(syn::parse_quote!(const __OUTPUT: usize));
+	let (impl_generics, _, _) = generics.split_for_impl();
+	// Only bound the field types if there's something generic about them,
+	// as bounds on concrete (and possibly private) types are just noise.
+	let field_bounds = if input.generics.params.is_empty() {
+		Vec::new()
+	} else {
+		bounds
+	};
+	let where_clause = match where_clause {
+		Some(where_clause) => {
+			let predicates = where_clause.predicates.iter();
+			quote!(where #(#predicates,)* #(#field_bounds,)*)
+		}
+		None => quote!(where #(#field_bounds,)*),
+	};
+
+	Ok(quote! {
+		impl #impl_generics ::nanorand::RandomGen<__Generator, __OUTPUT> for #name #type_generics #where_clause {
+			fn random(rng: &mut __Generator) -> Self {
+				#body
+			}
+		}
+	})
+}
+
+/// Builds an expression that constructs `path` with randomly generated fields.
+fn construct(
+	path: TokenStream2,
+	fields: &Fields,
+	bounds: &mut Vec<TokenStream2>,
+) -> Result<TokenStream2> {
+	let mut values = Vec::with_capacity(fields.len());
+	for field in fields {
+		let ty = &field.ty;
+		let value = match range(&field.attrs)? {
+			Some(range) => {
+				bounds.push(quote!(#ty: ::nanorand::RandomRange<__Generator, __OUTPUT>));
+				quote_spanned!(field.span()=> ::nanorand::Rng::generate_range(rng, #range))
+			}
+			None => {
+				bounds.push(quote!(#ty: ::nanorand::RandomGen<__Generator, __OUTPUT>));
+				quote_spanned!(field.span()=> ::nanorand::Rng::generate(rng))
+			}
+		};
+		values.push(match &field.ident {
+			Some(ident) => quote!(#ident: #value),
+			None => value,
+		});
+	}
+	Ok(match fields {
+		Fields::Named(_) => quote!(#path { #(#values),* }),
+		Fields::Unnamed(_) => quote!(#path ( #(#values),* )),
+		Fields::Unit => path,
+	})
+}
+
+/// Finds the range expression in a field's `#[range(...)]` attribute, if it has one.
+fn range(attrs: &[Attribute]) -> Result<Option<TokenStream2>> {
+	let mut found = None;
+	for attr in attrs.iter().filter(|attr| attr.path.is_ident("range")) {
+		if found.is_some() {
+			return Err(Error::new(attr.span(), "a field can only have one range"));
+		}
+		found = Some(attr.parse_args::<TokenStream2>()?);
+	}
+	Ok(found)
+}
+
+/// Finds the weight in a variant's `#[weight = N]` attribute, defaulting to 1.
+fn weight(attrs: &[Attribute]) -> Result<u64> {
+	let mut found = None;
+	for attr in attrs.iter().filter(|attr| attr.path.is_ident("weight")) {
+		if found.is_some() {
+			return Err(Error::new(
+				attr.span(),
+				"a variant can only have one weight",
+			));
+		}
+		match attr.parse_meta()? {
+			Meta::NameValue(meta) => match meta.lit {
+				Lit::Int(weight) => found = Some(weight.base10_parse::<u64>()?),
+				lit => return Err(Error::new(lit.span(), "weights must be integers")),
+			},
+			meta => {
+				return Err(Error::new(
+					meta.span(),
+					"weights must be written like #[weight = 10]",
+				))
+			}
+		}
+	}
+	Ok(found.unwrap_or(1))
+}
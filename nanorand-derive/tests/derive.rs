@@ -0,0 +1,140 @@
+use nanorand::{RandomGen, Rng, WyRand};
+
+#[derive(RandomGen, Debug, PartialEq)]
+enum Suit {
+	Clubs,
+	Diamonds,
+	Hearts,
+	Spades,
+}
+
+#[derive(RandomGen, Debug, PartialEq)]
+enum Weighted {
+	#[weight = 3]
+	Often,
+	#[weight = 1]
+	Sometimes,
+	#[weight = 0]
+	#[allow(dead_code)]
+	Never,
+}
+
+#[derive(RandomGen, Debug)]
+struct Card {
+	suit: Suit,
+	#[range(1..=13)]
+	rank: u8,
+}
+
+#[derive(RandomGen, Debug)]
+struct Pair<T>(T, #[range(-5..5)] i32);
+
+#[derive(RandomGen, Debug)]
+enum Shape {
+	Point,
+	Circle(#[range(0.0..10.0)] f64),
+	Rectangle { width: u16, height: u16 },
+}
+
+#[derive(RandomGen)]
+struct Unit;
+
+#[test]
+fn ensure_structs_generate_properly() {
+	let mut rng = WyRand::new_seed(42);
+	let mut suits = [false; 4];
+	let mut ranks = [false; 13];
+	let mut offsets = [false; 10];
+	for _ in 0..1000 {
+		let card: Card = rng.generate();
+		assert!(
+			(1..=13).contains(&card.rank),
+			"{:?} had an invalid rank",
+			card
+		);
+		suits[card.suit as usize] = true;
+		ranks[usize::from(card.rank - 1)] = true;
+
+		let pair: Pair<Card> = rng.generate();
+		assert!((-5..5).contains(&pair.1), "{:?} was outside of -5..5", pair);
+		offsets[(pair.1 + 5) as usize] = true;
+
+		let _: Unit = rng.generate();
+	}
+	assert!(
+		suits.iter().all(|&seen| seen),
+		"not every suit was generated"
+	);
+	assert!(
+		ranks.iter().all(|&seen| seen),
+		"not every rank was generated"
+	);
+	assert!(
+		offsets.iter().all(|&seen| seen),
+		"not every value in -5..5 was generated"
+	);
+}
+
+#[test]
+fn ensure_enums_generate_properly() {
+	let mut rng = WyRand::new_seed(42);
+	let mut suits = [0_usize; 4];
+	let mut often = 0_usize;
+	let mut shapes = [0_usize; 3];
+	let mut widths = std::collections::HashSet::new();
+	let mut heights = std::collections::HashSet::new();
+	for _ in 0..40000 {
+		suits[rng.generate::<Suit>() as usize] += 1;
+		match rng.generate::<Weighted>() {
+			Weighted::Often => often += 1,
+			Weighted::Sometimes => {}
+			Weighted::Never => panic!("a variant with a weight of 0 was picked"),
+		}
+		match rng.generate::<Shape>() {
+			Shape::Point => shapes[0] += 1,
+			Shape::Circle(radius) => {
+				assert!(
+					(0.0..10.0).contains(&radius),
+					"{} was outside of 0.0..10.0",
+					radius
+				);
+				shapes[1] += 1;
+			}
+			Shape::Rectangle { width, height } => {
+				widths.insert(width);
+				heights.insert(height);
+				shapes[2] += 1;
+			}
+		}
+	}
+	for count in shapes.iter() {
+		assert!(
+			(12833..13833).contains(count),
+			"{:?} wasn't uniform enough",
+			shapes
+		);
+	}
+	// With over 13000 rectangles drawn from 65536 values each, repeats should be rare.
+	assert!(
+		widths.len() > 10000,
+		"only {} distinct widths",
+		widths.len()
+	);
+	assert!(
+		heights.len() > 10000,
+		"only {} distinct heights",
+		heights.len()
+	);
+	for count in suits.iter() {
+		assert!(
+			(9500..10500).contains(count),
+			"{:?} wasn't uniform enough",
+			suits
+		);
+	}
+	assert!(
+		(29500..30500).contains(&often),
+		"{} was too far from 30000",
+		often
+	);
+}
@@ -1,6 +1,6 @@
 #![no_main]
 use libfuzzer_sys::fuzz_target;
-use nanorand::{tls_rng, Rng};
+use nanorand::{tls_rng, RangeError, Rng};
 
 fuzz_target!(|data: (u16, u16, bool)| {
 	let (a, b, range_type) = data;
@@ -29,29 +29,29 @@ fuzz_target!(|data: (u16, u16, bool)| {
 				upper
 			);
 		}
+	} else if upper == lower {
+		assert_eq!(
+			tls_rng().try_generate_range(lower..upper),
+			Err(RangeError::Empty),
+			"{}..{} wasn't empty",
+			lower,
+			upper
+		);
 	} else {
 		let number = tls_rng().generate_range(lower..upper);
-		if upper == lower {
-			assert_eq!(
-				number, upper,
-				"{} was outside of range {}=={}",
-				number, lower, upper
-			);
-		} else {
-			assert!(
-				number >= lower,
-				"{} was bigger than range {}..{}",
-				number,
-				lower,
-				upper
-			);
-			assert!(
-				number < upper,
-				"{} was smaller than range {}..{}",
-				number,
-				lower,
-				upper
-			);
-		}
+		assert!(
+			number >= lower,
+			"{} was bigger than range {}..{}",
+			number,
+			lower,
+			upper
+		);
+		assert!(
+			number < upper,
+			"{} was smaller than range {}..{}",
+			number,
+			lower,
+			upper
+		);
 	};
 });
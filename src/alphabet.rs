@@ -0,0 +1,94 @@
+//! These are meant to be used with [`Rng::bytes_from_alphabet`](crate::Rng::bytes_from_alphabet)
+//! and [`Rng::string_from_alphabet`](crate::Rng::string_from_alphabet).
+//!
+//! ```rust
+//! use nanorand::{alphabet, Rng, WyRand};
+//!
+//! let mut rng = WyRand::new();
+//! let token = rng.string_from_alphabet(alphabet::ALPHANUMERIC, 32);
+//! assert_eq!(token.len(), 32);
+//! ```
+
+/// Uppercase and lowercase ASCII letters, and ASCII digits.
+pub const ALPHANUMERIC: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Lowercase hexadecimal digits.
+pub const HEX: &str = "0123456789abcdef";
+
+/// The URL and filename safe base64 alphabet, as described in [RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648#section-5).
+pub const BASE64_URL: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Rng, WyRand};
+
+	#[test]
+	fn ensure_strings_use_alphabet() {
+		let mut rng = WyRand::new();
+		for length in 0..100 {
+			let string = rng.alphanumeric(length);
+			assert_eq!(string.len(), length);
+			assert!(
+				string.chars().all(|c| c.is_ascii_alphanumeric()),
+				"{} wasn't alphanumeric",
+				string
+			);
+
+			let string = rng.hex(length);
+			assert_eq!(string.len(), length);
+			assert!(
+				string.chars().all(|c| HEX.contains(c)),
+				"{} wasn't hex",
+				string
+			);
+
+			let string = rng.base64_url(length);
+			assert_eq!(string.len(), length);
+			assert!(
+				string.chars().all(|c| BASE64_URL.contains(c)),
+				"{} wasn't base64url",
+				string
+			);
+
+			let string = rng.string_from_alphabet("αβγ🦀", length);
+			assert_eq!(string.chars().count(), length);
+			assert!(
+				string.chars().all(|c| "αβγ🦀".contains(c)),
+				"{} wasn't in the alphabet",
+				string
+			);
+
+			let bytes = rng.bytes_from_alphabet(b"\x00\xFF", length);
+			assert_eq!(bytes.len(), length);
+			assert!(
+				bytes.iter().all(|b| *b == 0x00 || *b == 0xFF),
+				"{:?} wasn't in the alphabet",
+				bytes
+			);
+		}
+	}
+
+	#[test]
+	fn ensure_alphabet_is_uniform() {
+		let mut rng = WyRand::new_seed(42);
+		let mut counts = [0_usize; 3];
+		for byte in rng.bytes_from_alphabet(b"abc", 30000) {
+			counts[(byte - b'a') as usize] += 1;
+		}
+		for count in counts.iter() {
+			assert!(
+				(9500..10500).contains(count),
+				"{:?} wasn't uniform enough",
+				counts
+			);
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn ensure_empty_alphabet_panics() {
+		let mut rng = WyRand::new();
+		rng.string_from_alphabet("", 1);
+	}
+}
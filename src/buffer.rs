@@ -97,6 +97,10 @@ impl<
 		const SEED_SIZE: usize,
 	> SeedableRng<SEED_SIZE, OUTPUT> for BufferedRng<InternalGenerator, OUTPUT>
 {
+	fn from_seed(seed: [u8; SEED_SIZE]) -> Self {
+		Self::new(InternalGenerator::from_seed(seed))
+	}
+
 	fn reseed(&mut self, seed: [u8; SEED_SIZE]) {
 		self.rng.reseed(seed);
 	}
@@ -1,3 +1,12 @@
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod sse2;
+
 const CHACHA_TAU: &[u8] = b"expand 32-byte k";
 
 fn chacha_quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
@@ -25,8 +34,8 @@ const fn chacha_pack(unpacked: &[u8], idx: usize) -> u32 {
 		| ((unpacked[idx + 3] as u32) << 24)
 }
 
-/// Do one ChaCha round on the input data.
-pub fn chacha_block<const ROUNDS: u8>(input: [u32; 16]) -> [u32; 16] {
+/// Do the ChaCha rounds on the input data, without adding the input back in.
+fn chacha_rounds<const ROUNDS: u8>(input: [u32; 16]) -> [u32; 16] {
 	let mut x = input;
 	assert_eq!(ROUNDS % 2, 0, "ChaCha rounds must be divisble by 2!");
 	for _ in (0..ROUNDS).step_by(2) {
@@ -41,14 +50,107 @@ pub fn chacha_block<const ROUNDS: u8>(input: [u32; 16]) -> [u32; 16] {
 		chacha_quarter_round(&mut x, 2, 7, 8, 13);
 		chacha_quarter_round(&mut x, 3, 4, 9, 14);
 	}
+	x
+}
+
+/// Do one ChaCha round on the input data.
+pub fn chacha_block<const ROUNDS: u8>(input: [u32; 16]) -> [u32; 16] {
+	let mut x = chacha_rounds::<ROUNDS>(input);
 	x.iter_mut()
 		.zip(input.iter())
 		.for_each(|(l, r)| *l = l.wrapping_add(*r));
 	x
 }
 
-/// Initialize the ChaCha internal state, with a 256-bit key and 64-bit nonce.
-pub const fn chacha_init(key: [u8; 32], counter: [u8; 8], nonce: [u8; 8]) -> [u32; 16] {
+/// Generate consecutive blocks of keystream into `output`, whose length must be a multiple of 64 bytes.
+///
+/// Block `n` uses the 64-bit counter from `input` plus `n`, so with the IETF layout, the caller has to make sure
+/// the 32-bit counter doesn't overflow into the nonce. This gives exactly the same output as calling [`chacha_block`]
+/// for each block, but uses AVX2 or SSE2 to generate several blocks at once where they're available.
+pub fn chacha_blocks<const ROUNDS: u8>(mut input: [u32; 16], output: &mut [u8]) {
+	// The SIMD backends don't go through `chacha_rounds`, so check this before picking one.
+	assert_eq!(ROUNDS % 2, 0, "ChaCha rounds must be divisble by 2!");
+	assert_eq!(
+		output.len() % 64,
+		0,
+		"ChaCha output must be a whole number of blocks!"
+	);
+	let mut offset = 0;
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	{
+		if has_avx2() {
+			while output.len() - offset >= avx2::BLOCKS * 64 {
+				// SAFETY: we've just checked that the CPU supports AVX2.
+				unsafe {
+					avx2::chacha_blocks::<ROUNDS>(
+						&input,
+						&mut output[offset..offset + avx2::BLOCKS * 64],
+					)
+				};
+				chacha_add_to_counter(&mut input, avx2::BLOCKS as u64);
+				offset += avx2::BLOCKS * 64;
+			}
+		}
+		if has_sse2() {
+			while output.len() - offset >= sse2::BLOCKS * 64 {
+				// SAFETY: we've just checked that the CPU supports SSE2.
+				unsafe {
+					sse2::chacha_blocks::<ROUNDS>(
+						&input,
+						&mut output[offset..offset + sse2::BLOCKS * 64],
+					)
+				};
+				chacha_add_to_counter(&mut input, sse2::BLOCKS as u64);
+				offset += sse2::BLOCKS * 64;
+			}
+		}
+	}
+
+	for block in output[offset..].chunks_exact_mut(64) {
+		block
+			.chunks_exact_mut(4)
+			.zip(chacha_block::<ROUNDS>(input).iter())
+			.for_each(|(bytes, word)| bytes.copy_from_slice(&word.to_le_bytes()));
+		chacha_add_to_counter(&mut input, 1);
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(clippy::missing_const_for_fn)]
+fn has_avx2() -> bool {
+	#[cfg(feature = "std")]
+	{
+		std::is_x86_feature_detected!("avx2")
+	}
+	#[cfg(not(feature = "std"))]
+	{
+		cfg!(target_feature = "avx2")
+	}
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(clippy::missing_const_for_fn)]
+fn has_sse2() -> bool {
+	#[cfg(feature = "std")]
+	{
+		std::is_x86_feature_detected!("sse2")
+	}
+	#[cfg(not(feature = "std"))]
+	{
+		cfg!(target_feature = "sse2")
+	}
+}
+
+/// Add `blocks` to the 64-bit counter of the internal ChaCha state, wrapping around on overflow.
+pub fn chacha_add_to_counter(state: &mut [u32; 16], blocks: u64) {
+	let counter = (((state[13] as u64) << 32) | (state[12] as u64)).wrapping_add(blocks);
+	state[12] = counter as u32;
+	state[13] = (counter >> 32) as u32;
+}
+
+/// Fill in the constants and the 256-bit key, leaving the counter and nonce words empty.
+const fn chacha_init_key(key: [u8; 32]) -> [u32; 16] {
 	let mut state = [0u32; 16];
 	state[0] = chacha_pack(CHACHA_TAU, 0);
 	state[1] = chacha_pack(CHACHA_TAU, 4);
@@ -63,7 +165,12 @@ pub const fn chacha_init(key: [u8; 32], counter: [u8; 8], nonce: [u8; 8]) -> [u3
 	state[9] = chacha_pack(&key, 20);
 	state[10] = chacha_pack(&key, 24);
 	state[11] = chacha_pack(&key, 28);
+	state
+}
 
+/// Initialize the ChaCha internal state, with a 256-bit key and 64-bit nonce.
+pub const fn chacha_init(key: [u8; 32], counter: [u8; 8], nonce: [u8; 8]) -> [u32; 16] {
+	let mut state = chacha_init_key(key);
 	// 64-bit counter
 	state[12] = chacha_pack(&counter, 0);
 	state[13] = chacha_pack(&counter, 4);
@@ -73,6 +180,53 @@ pub const fn chacha_init(key: [u8; 32], counter: [u8; 8], nonce: [u8; 8]) -> [u3
 	state
 }
 
+/// Initialize the ChaCha internal state in the RFC 8439 (IETF) layout, with a 256-bit key, 32-bit counter and 96-bit nonce.
+pub const fn chacha_init_ietf(key: [u8; 32], counter: [u8; 4], nonce: [u8; 12]) -> [u32; 16] {
+	let mut state = chacha_init_key(key);
+	// 32-bit counter
+	state[12] = chacha_pack(&counter, 0);
+	// Nonce
+	state[13] = chacha_pack(&nonce, 0);
+	state[14] = chacha_pack(&nonce, 4);
+	state[15] = chacha_pack(&nonce, 8);
+	state
+}
+
+/// Derive a 256-bit subkey from a 256-bit key and the first 128 bits of an XChaCha nonce.
+/// With 20 rounds, this is HChaCha20.
+pub fn hchacha<const ROUNDS: u8>(key: [u8; 32], nonce: [u8; 16]) -> [u8; 32] {
+	let mut state = chacha_init_key(key);
+	state[12] = chacha_pack(&nonce, 0);
+	state[13] = chacha_pack(&nonce, 4);
+	state[14] = chacha_pack(&nonce, 8);
+	state[15] = chacha_pack(&nonce, 12);
+	let state = chacha_rounds::<ROUNDS>(state);
+
+	let mut subkey = [0u8; 32];
+	subkey
+		.chunks_exact_mut(4)
+		.zip(state[..4].iter().chain(&state[12..]))
+		.for_each(|(bytes, word)| bytes.copy_from_slice(&word.to_le_bytes()));
+	subkey
+}
+
+/// Initialize the ChaCha internal state for XChaCha, with a 256-bit key, 64-bit counter and 192-bit nonce.
+///
+/// The key and first 128 bits of the nonce go through [`hchacha`], and the result is used as the key
+/// for the original layout, with the last 64 bits of the nonce. While the counter stays below 2^32,
+/// this gives the same keystream as the IETF-layout XChaCha in draft-irtf-cfrg-xchacha.
+pub fn xchacha_init<const ROUNDS: u8>(
+	key: [u8; 32],
+	counter: [u8; 8],
+	nonce: [u8; 24],
+) -> [u32; 16] {
+	let mut hchacha_nonce = [0u8; 16];
+	let mut chacha_nonce = [0u8; 8];
+	hchacha_nonce.copy_from_slice(&nonce[..16]);
+	chacha_nonce.copy_from_slice(&nonce[16..]);
+	chacha_init(hchacha::<ROUNDS>(key, hchacha_nonce), counter, chacha_nonce)
+}
+
 /// Increment the 64-bit counter of the internal ChaCha20 state by 1.
 /// Returns `false` if it overflows, `true` otherwise.
 pub fn chacha_increment_counter(state: &mut [u32; 16]) -> bool {
@@ -80,13 +234,207 @@ pub fn chacha_increment_counter(state: &mut [u32; 16]) -> bool {
 	match counter.checked_add(1) {
 		Some(new_counter) => {
 			state[12] = (new_counter & 0xFFFFFFFF) as u32;
-			state[13] = ((counter >> 32) & 0xFFFFFFFF) as u32;
+			state[13] = ((new_counter >> 32) & 0xFFFFFFFF) as u32;
+			true
+		}
+		None => false,
+	}
+}
+
+/// Increment the 32-bit counter of an internal ChaCha20 state in the IETF layout by 1.
+/// Returns `false` if it overflows, `true` otherwise.
+pub fn chacha_increment_counter_ietf(state: &mut [u32; 16]) -> bool {
+	match state[12].checked_add(1) {
+		Some(new_counter) => {
+			state[12] = new_counter;
 			true
 		}
 		None => false,
 	}
 }
 
+/// An error returned by [`ChaChaCipher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CipherError {
+	/// The block counter would overflow, so the keystream would start repeating.
+	CounterOverflow,
+}
+
+impl Display for CipherError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::CounterOverflow => write!(f, "the keystream's block counter would overflow"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CipherError {}
+
+/// The ChaCha stream cipher, which XORs a keystream into data to encrypt or decrypt it.
+///
+/// It keeps track of its position in the keystream, so data can be processed in pieces of any size,
+/// and it refuses to go past the end of the keystream instead of letting the counter wrap around.
+///
+/// ```rust
+/// use nanorand::crypto::chacha::ChaChaCipher;
+///
+/// let key = [42; 32];
+/// let nonce = [7; 12];
+/// let mut data = *b"attack at dawn";
+/// ChaChaCipher::<20>::new_ietf(key, [0; 4], nonce)
+///     .apply_keystream(&mut data)
+///     .unwrap();
+/// assert_ne!(&data, b"attack at dawn");
+///
+/// // Decrypting is the same operation, in as many pieces as you like.
+/// let mut cipher = ChaChaCipher::<20>::new_ietf(key, [0; 4], nonce);
+/// let (start, end) = data.split_at_mut(6);
+/// cipher.apply_keystream(start).unwrap();
+/// cipher.apply_keystream(end).unwrap();
+/// assert_eq!(&data, b"attack at dawn");
+/// ```
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[cfg_attr(feature = "zeroize", zeroize(drop))]
+pub struct ChaChaCipher<const ROUNDS: u8> {
+	state: [u32; 16],
+	/// Whether the state is in the RFC 8439 layout, with a 32-bit counter and a 96-bit nonce.
+	ietf: bool,
+	/// The position of the next keystream byte, counted from block zero.
+	byte_pos: u128,
+}
+
+impl<const ROUNDS: u8> ChaChaCipher<ROUNDS> {
+	/// Create a new [`ChaChaCipher`] in the original layout, using the provided key, 64-bit counter and 64-bit nonce.
+	#[must_use]
+	pub const fn new(key: [u8; 32], counter: [u8; 8], nonce: [u8; 8]) -> Self {
+		let state = chacha_init(key, counter, nonce);
+		Self {
+			state,
+			ietf: false,
+			byte_pos: (((state[13] as u64) << 32) | (state[12] as u64)) as u128 * 64,
+		}
+	}
+
+	/// Create a new [`ChaChaCipher`] in the RFC 8439 (IETF) layout, using the provided key, 32-bit counter and 96-bit nonce.
+	///
+	/// The counter only has room for 2^32 blocks, so at most 256 GiB can be processed with one nonce.
+	#[must_use]
+	pub const fn new_ietf(key: [u8; 32], counter: [u8; 4], nonce: [u8; 12]) -> Self {
+		let state = chacha_init_ietf(key, counter, nonce);
+		Self {
+			state,
+			ietf: true,
+			byte_pos: state[12] as u128 * 64,
+		}
+	}
+
+	/// Create a new XChaCha [`ChaChaCipher`], using the provided key, 64-bit counter and 192-bit nonce.
+	#[must_use]
+	pub fn new_xchacha(key: [u8; 32], counter: [u8; 8], nonce: [u8; 24]) -> Self {
+		let state = xchacha_init::<ROUNDS>(key, counter, nonce);
+		Self {
+			state,
+			ietf: false,
+			byte_pos: (((state[13] as u64) << 32) | (state[12] as u64)) as u128 * 64,
+		}
+	}
+
+	/// The length of the whole keystream, in bytes.
+	const fn keystream_len(&self) -> u128 {
+		if self.ietf {
+			1 << 38
+		} else {
+			1 << 70
+		}
+	}
+
+	/// Returns the position of the next keystream byte that [`ChaChaCipher::apply_keystream`] will use.
+	#[must_use]
+	pub const fn get_byte_pos(&self) -> u128 {
+		self.byte_pos
+	}
+
+	/// Moves to a byte position in the keystream, such as the offset of a piece of data within a larger message.
+	///
+	/// # Errors
+	/// This function will return [`CipherError::CounterOverflow`] if the position is past the end of the keystream,
+	/// which is 2^70 bytes long, or 2^38 bytes in the IETF layout.
+	pub fn set_byte_pos(&mut self, byte_pos: u128) -> Result<(), CipherError> {
+		if byte_pos > self.keystream_len() {
+			return Err(CipherError::CounterOverflow);
+		}
+		self.byte_pos = byte_pos;
+		Ok(())
+	}
+
+	/// Points the counter at the block containing the next keystream byte.
+	fn set_counter(&mut self) {
+		let counter = (self.byte_pos / 64) as u64;
+		self.state[12] = counter as u32;
+		if !self.ietf {
+			self.state[13] = (counter >> 32) as u32;
+		}
+	}
+
+	/// XORs the keystream into `data`, continuing from the current position, and moves past the bytes that were used.
+	/// Encryption and decryption are the same operation.
+	///
+	/// # Errors
+	/// This function will return [`CipherError::CounterOverflow`] if `data` would run past the end of the keystream,
+	/// in which case neither `data` nor the position is changed.
+	pub fn apply_keystream(&mut self, data: &mut [u8]) -> Result<(), CipherError> {
+		match self.byte_pos.checked_add(data.len() as u128) {
+			Some(end) if end <= self.keystream_len() => {}
+			_ => return Err(CipherError::CounterOverflow),
+		}
+
+		// Whole blocks are generated several at a time, and partial blocks at either end one at a time.
+		let mut keystream = [0u8; 512];
+		let mut data = data;
+		while !data.is_empty() {
+			let offset = (self.byte_pos % 64) as usize;
+			let blocks = if offset == 0 { data.len() / 64 } else { 0 };
+			let (generated, length) = if blocks > 0 {
+				let length = blocks.min(keystream.len() / 64) * 64;
+				(length, length)
+			} else {
+				(64, (64 - offset).min(data.len()))
+			};
+			self.set_counter();
+			chacha_blocks::<ROUNDS>(self.state, &mut keystream[..generated]);
+
+			let (chunk, rest) = data.split_at_mut(length);
+			chunk
+				.iter_mut()
+				.zip(&keystream[offset..offset + length])
+				.for_each(|(byte, key)| *byte ^= key);
+			data = rest;
+			self.byte_pos += length as u128;
+		}
+		Ok(())
+	}
+}
+
+impl<const ROUNDS: u8> Clone for ChaChaCipher<ROUNDS> {
+	fn clone(&self) -> Self {
+		Self {
+			state: self.state,
+			ietf: self.ietf,
+			byte_pos: self.byte_pos,
+		}
+	}
+}
+
+impl<const ROUNDS: u8> Debug for ChaChaCipher<ROUNDS> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ChaChaCipher")
+			.field("rounds", &ROUNDS)
+			.field("byte_pos", &self.byte_pos)
+			.finish()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -111,9 +459,191 @@ mod tests {
 			keystream.resize(expected_keystream.len(), 0);
 
 			assert_eq!(keystream, expected_keystream);
+
+			let mut data = vec![0u8; expected_keystream.len()];
+			ChaChaCipher::<20>::new(key, counter, nonce)
+				.apply_keystream(&mut data)
+				.unwrap();
+			assert_eq!(data, expected_keystream);
 		};
 	}
 
+	macro_rules! rfc8439_test_vector {
+		($key_hex: tt, $counter: expr, $nonce_hex: tt, $keystream_hex: tt) => {
+			let key: [u8; 32] = hex::decode($key_hex).unwrap().try_into().unwrap();
+			let counter = u32::to_le_bytes($counter);
+			let nonce: [u8; 12] = hex::decode($nonce_hex).unwrap().try_into().unwrap();
+			let expected_keystream: Vec<u8> = hex::decode($keystream_hex).unwrap();
+
+			let state = chacha_init_ietf(key, counter, nonce);
+			let keystream: Vec<u8> = chacha_block::<20>(state)
+				.iter()
+				.flat_map(|packed| packed.to_le_bytes())
+				.collect();
+
+			assert_eq!(keystream, expected_keystream);
+
+			let mut data = [0u8; 64];
+			ChaChaCipher::<20>::new_ietf(key, counter, nonce)
+				.apply_keystream(&mut data)
+				.unwrap();
+			assert_eq!(data[..], expected_keystream[..]);
+		};
+	}
+
+	#[test]
+	fn test_rfc8439_chacha20_test_vectors() {
+		// Section 2.3.2
+		rfc8439_test_vector!(
+			"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+			1,
+			"000000090000004a00000000",
+			"10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4ed2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+		);
+
+		// Appendix A.1, test vectors #2 to #5
+		rfc8439_test_vector!(
+			"0000000000000000000000000000000000000000000000000000000000000000",
+			1,
+			"000000000000000000000000",
+			"9f07e7be5551387a98ba977c732d080dcb0f29a048e3656912c6533e32ee7aed29b721769ce64e43d57133b074d839d531ed1f28510afb45ace10a1f4b794d6f"
+		);
+
+		rfc8439_test_vector!(
+			"0000000000000000000000000000000000000000000000000000000000000001",
+			1,
+			"000000000000000000000000",
+			"3aeb5224ecf849929b9d828db1ced4dd832025e8018b8160b82284f3c949aa5a8eca00bbb4a73bdad192b5c42f73f2fd4e273644c8b36125a64addeb006c13a0"
+		);
+
+		rfc8439_test_vector!(
+			"00ff000000000000000000000000000000000000000000000000000000000000",
+			2,
+			"000000000000000000000000",
+			"72d54dfbf12ec44b362692df94137f328fea8da73990265ec1bbbea1ae9af0ca13b25aa26cb4a648cb9b9d1be65b2c0924a66c54d545ec1b7374f4872e99f096"
+		);
+
+		rfc8439_test_vector!(
+			"0000000000000000000000000000000000000000000000000000000000000000",
+			0,
+			"000000000000000000000002",
+			"c2c64d378cd536374ae204b9ef933fcd1a8b2288b3dfa49672ab765b54ee27c78a970e0e955c14f3a88e741b97c286f75f8fc299e8148362fa198a39531bed6d"
+		);
+	}
+
+	#[test]
+	fn test_hchacha20_test_vector() {
+		// draft-irtf-cfrg-xchacha, section 2.2.1
+		let key: [u8; 32] =
+			hex::decode("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+				.unwrap()
+				.try_into()
+				.unwrap();
+		let nonce: [u8; 16] = hex::decode("000000090000004a0000000031415927")
+			.unwrap()
+			.try_into()
+			.unwrap();
+		assert_eq!(
+			hex::encode(hchacha::<20>(key, nonce)),
+			"82413b4227b27bfed30e42508a877d73a0f9e4d58a74a853c12ec41326d3ecdc"
+		);
+	}
+
+	#[test]
+	fn test_xchacha20_test_vector() {
+		// draft-irtf-cfrg-xchacha, appendix A.3.2, which starts from block 1
+		let key: [u8; 32] =
+			hex::decode("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f")
+				.unwrap()
+				.try_into()
+				.unwrap();
+		let nonce: [u8; 24] = hex::decode("404142434445464748494a4b4c4d4e4f5051525354555658")
+			.unwrap()
+			.try_into()
+			.unwrap();
+		let state = xchacha_init::<20>(key, u64::to_le_bytes(1), nonce);
+		let keystream: Vec<u8> = chacha_block::<20>(state)
+			.iter()
+			.flat_map(|packed| packed.to_le_bytes())
+			.collect();
+		assert_eq!(
+			hex::encode(keystream),
+			"29624b4b1b140ace53740e405b2168540fd7d630c1f536fecd722fc3cddba7f4cca98cf9e47e5e64d115450f9b125b54449ff76141ca620a1f9cfcab2a1a8a25"
+		);
+	}
+
+	fn scalar_keystream<const ROUNDS: u8>(mut state: [u32; 16], blocks: usize) -> Vec<u8> {
+		let mut keystream = Vec::with_capacity(blocks * 64);
+		for _ in 0..blocks {
+			chacha_block::<ROUNDS>(state)
+				.iter()
+				.for_each(|packed| keystream.extend_from_slice(&packed.to_le_bytes()));
+			chacha_increment_counter(&mut state);
+		}
+		keystream
+	}
+
+	fn check_chacha_blocks<const ROUNDS: u8>(state: [u32; 16]) {
+		for blocks in [0, 1, 3, 4, 5, 8, 12, 13, 31] {
+			let mut keystream = vec![0; blocks * 64];
+			chacha_blocks::<ROUNDS>(state, &mut keystream);
+			assert_eq!(keystream, scalar_keystream::<ROUNDS>(state, blocks));
+		}
+	}
+
+	#[test]
+	fn test_chacha_blocks_match_scalar() {
+		// Start just before the counter carries into its high word, so every batch size crosses it.
+		let state = chacha_init([7; 32], u64::to_le_bytes(u32::MAX as u64 - 5), [3; 8]);
+		check_chacha_blocks::<8>(state);
+		check_chacha_blocks::<12>(state);
+		check_chacha_blocks::<20>(state);
+	}
+
+	#[test]
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	fn test_simd_backends_match_scalar() {
+		let state = chacha_init([7; 32], u64::to_le_bytes(u32::MAX as u64 - 2), [3; 8]);
+		// `chacha_blocks` always prefers AVX2, so check the SSE2 backend on its own too.
+		if is_x86_feature_detected!("sse2") {
+			let mut keystream = [0; sse2::BLOCKS * 64];
+			unsafe { sse2::chacha_blocks::<20>(&state, &mut keystream) };
+			assert_eq!(
+				keystream[..],
+				scalar_keystream::<20>(state, sse2::BLOCKS)[..]
+			);
+		}
+		if is_x86_feature_detected!("avx2") {
+			let mut keystream = [0; avx2::BLOCKS * 64];
+			unsafe { avx2::chacha_blocks::<20>(&state, &mut keystream) };
+			assert_eq!(
+				keystream[..],
+				scalar_keystream::<20>(state, avx2::BLOCKS)[..]
+			);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "ChaCha rounds must be divisble by 2!")]
+	fn test_chacha_blocks_rejects_odd_rounds() {
+		let state = chacha_init([1; 32], [0; 8], [0; 8]);
+		chacha_blocks::<7>(state, &mut [0; 64 * 8]);
+	}
+
+	#[test]
+	fn test_counters_increment_correctly() {
+		let mut state = chacha_init([0; 32], u64::to_le_bytes(u32::MAX as u64), [0; 8]);
+		assert!(chacha_increment_counter(&mut state));
+		assert_eq!((state[12], state[13]), (0, 1));
+		let mut state = chacha_init([0; 32], [0xff; 8], [0; 8]);
+		assert!(!chacha_increment_counter(&mut state));
+
+		let mut state = chacha_init_ietf([0; 32], [0xfe, 0xff, 0xff, 0xff], [0xff; 12]);
+		assert!(chacha_increment_counter_ietf(&mut state));
+		assert!(!chacha_increment_counter_ietf(&mut state));
+		assert_eq!(state[12..], [u32::MAX; 4]);
+	}
+
 	#[test]
 	fn test_ietf_chacha20_test_vectors() {
 		ietf_test_vector!(
@@ -146,4 +676,111 @@ mod tests {
 			"f798a189f195e66982105ffb640bb7757f579da31602fc93ec01ac56f85ac3c134a4547b733b46413042c9440049176905d3be59ea1c53f15916155c2be8241a38008b9a26bc35941e2444177c8ade6689de95264986d95889fb60e84629c9bd9a5acb1cc118be563eb9b3a4a472f82e09a7e778492b562ef7130e88dfe031c79db9d4f7c7a899151b9a475032b63fc385245fe054e3dd5a97a5f576fe064025d3ce042c566ab2c507b138db853e3d6959660996546cc9c4a6eafdc777c040d70eaf46f76dad3979e5c5360c3317166a1c894c94a371876a94df7628fe4eaaf2ccb27d5aaae0ad7ad0f9d4b6ad3b54098746d4524d38407a6deb3ab78fab78c9"
 		);
 	}
+
+	#[test]
+	fn test_cipher_rfc8439_encryption() {
+		// Section 2.4.2
+		let key: [u8; 32] =
+			hex::decode("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+				.unwrap()
+				.try_into()
+				.unwrap();
+		let nonce: [u8; 12] = hex::decode("000000000000004a00000000")
+			.unwrap()
+			.try_into()
+			.unwrap();
+		let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+		let ciphertext = hex::decode("6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0bf91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d807ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab77937365af90bbf74a35be6b40b8eedf2785e42874d").unwrap();
+
+		let mut data = plaintext.to_vec();
+		let mut cipher = ChaChaCipher::<20>::new_ietf(key, 1u32.to_le_bytes(), nonce);
+		cipher.apply_keystream(&mut data).unwrap();
+		assert_eq!(data, ciphertext);
+		assert_eq!(cipher.get_byte_pos(), 64 + plaintext.len() as u128);
+
+		cipher.set_byte_pos(64).unwrap();
+		cipher.apply_keystream(&mut data).unwrap();
+		assert_eq!(data, plaintext);
+	}
+
+	#[test]
+	fn test_cipher_handles_partial_blocks() {
+		let mut expected = vec![0u8; 2000];
+		ChaChaCipher::<8>::new([1; 32], [0; 8], [2; 8])
+			.apply_keystream(&mut expected)
+			.unwrap();
+
+		for chunk_size in [1, 7, 63, 64, 65, 200, 513, 1999] {
+			let mut cipher = ChaChaCipher::<8>::new([1; 32], [0; 8], [2; 8]);
+			let mut data = vec![0u8; expected.len()];
+			for chunk in data.chunks_mut(chunk_size) {
+				cipher.apply_keystream(chunk).unwrap();
+			}
+			assert_eq!(data, expected, "chunk size {}", chunk_size);
+		}
+
+		let mut cipher = ChaChaCipher::<8>::new([1; 32], [0; 8], [2; 8]);
+		cipher.set_byte_pos(1000).unwrap();
+		let mut data = vec![0u8; 500];
+		cipher.apply_keystream(&mut data).unwrap();
+		assert_eq!(data[..], expected[1000..1500]);
+	}
+
+	#[test]
+	fn test_cipher_crosses_the_low_counter_word() {
+		let mut cipher =
+			ChaChaCipher::<20>::new([3; 32], u64::to_le_bytes(u32::MAX as u64 - 2), [4; 8]);
+		let mut data = [0u8; 64 * 6];
+		cipher.apply_keystream(&mut data).unwrap();
+
+		let mut state = chacha_init([3; 32], u64::to_le_bytes(u32::MAX as u64 - 2), [4; 8]);
+		for block in data.chunks(64) {
+			let expected: Vec<u8> = chacha_block::<20>(state)
+				.iter()
+				.flat_map(|packed| packed.to_le_bytes())
+				.collect();
+			assert_eq!(block, &expected[..]);
+			chacha_increment_counter(&mut state);
+		}
+	}
+
+	#[test]
+	fn test_cipher_refuses_to_overflow_the_counter() {
+		let mut cipher = ChaChaCipher::<20>::new_ietf([5; 32], u32::MAX.to_le_bytes(), [6; 12]);
+		let mut data = [0u8; 64];
+		assert_eq!(cipher.apply_keystream(&mut data), Ok(()));
+		assert_eq!(cipher.get_byte_pos(), 1 << 38);
+
+		let mut byte = [0u8; 1];
+		assert_eq!(
+			cipher.apply_keystream(&mut byte),
+			Err(CipherError::CounterOverflow)
+		);
+		assert_eq!(byte, [0]);
+		assert_eq!(cipher.get_byte_pos(), 1 << 38);
+		assert_eq!(cipher.apply_keystream(&mut []), Ok(()));
+
+		let mut cipher = ChaChaCipher::<20>::new_ietf([5; 32], u32::MAX.to_le_bytes(), [6; 12]);
+		let mut data = [0u8; 65];
+		assert_eq!(
+			cipher.apply_keystream(&mut data),
+			Err(CipherError::CounterOverflow)
+		);
+		assert_eq!(data, [0; 65]);
+		assert_eq!(
+			cipher.set_byte_pos((1 << 38) + 1),
+			Err(CipherError::CounterOverflow)
+		);
+
+		let mut cipher = ChaChaCipher::<20>::new([5; 32], [0xff; 8], [6; 8]);
+		assert_eq!(cipher.apply_keystream(&mut [0u8; 64]), Ok(()));
+		assert_eq!(
+			cipher.apply_keystream(&mut [0u8; 1]),
+			Err(CipherError::CounterOverflow)
+		);
+		assert_eq!(
+			cipher.set_byte_pos((1 << 70) + 1),
+			Err(CipherError::CounterOverflow)
+		);
+	}
 }
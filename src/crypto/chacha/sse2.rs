@@ -0,0 +1,109 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// The number of blocks generated at once.
+pub(super) const BLOCKS: usize = 4;
+
+macro_rules! rotate_left {
+	($x:expr, 16) => {{
+		// Swapping the 16-bit halves of each word is cheaper than two shifts.
+		_mm_shufflehi_epi16(_mm_shufflelo_epi16($x, 0b10_11_00_01), 0b10_11_00_01)
+	}};
+	($x:expr, $bits:literal) => {{
+		let x = $x;
+		_mm_or_si128(_mm_slli_epi32(x, $bits), _mm_srli_epi32(x, 32 - $bits))
+	}};
+}
+
+#[inline(always)]
+unsafe fn quarter_round(x: &mut [__m128i; 16], a: usize, b: usize, c: usize, d: usize) {
+	x[a] = _mm_add_epi32(x[a], x[b]);
+	x[d] = rotate_left!(_mm_xor_si128(x[d], x[a]), 16);
+
+	x[c] = _mm_add_epi32(x[c], x[d]);
+	x[b] = rotate_left!(_mm_xor_si128(x[b], x[c]), 12);
+
+	x[a] = _mm_add_epi32(x[a], x[b]);
+	x[d] = rotate_left!(_mm_xor_si128(x[d], x[a]), 8);
+
+	x[c] = _mm_add_epi32(x[c], x[d]);
+	x[b] = rotate_left!(_mm_xor_si128(x[b], x[c]), 7);
+}
+
+/// Generate [`BLOCKS`] consecutive blocks of keystream into `output`, which must be exactly that many blocks long.
+///
+/// Each vector holds the same word from every block, so the rounds work on all of them at once,
+/// and the blocks are transposed back into order at the end.
+///
+/// # Safety
+/// The CPU must support SSE2.
+#[target_feature(enable = "sse2")]
+pub(super) unsafe fn chacha_blocks<const ROUNDS: u8>(input: &[u32; 16], output: &mut [u8]) {
+	assert_eq!(output.len(), BLOCKS * 64);
+	let mut initial = [_mm_setzero_si128(); 16];
+	for (vector, word) in initial.iter_mut().zip(input.iter()) {
+		*vector = _mm_set1_epi32(*word as i32);
+	}
+	let counter = ((input[13] as u64) << 32) | (input[12] as u64);
+	let counters = [
+		counter,
+		counter.wrapping_add(1),
+		counter.wrapping_add(2),
+		counter.wrapping_add(3),
+	];
+	initial[12] = _mm_setr_epi32(
+		counters[0] as i32,
+		counters[1] as i32,
+		counters[2] as i32,
+		counters[3] as i32,
+	);
+	initial[13] = _mm_setr_epi32(
+		(counters[0] >> 32) as i32,
+		(counters[1] >> 32) as i32,
+		(counters[2] >> 32) as i32,
+		(counters[3] >> 32) as i32,
+	);
+
+	let mut x = initial;
+	for _ in (0..ROUNDS).step_by(2) {
+		// Odd rounds
+		quarter_round(&mut x, 0, 4, 8, 12);
+		quarter_round(&mut x, 1, 5, 9, 13);
+		quarter_round(&mut x, 2, 6, 10, 14);
+		quarter_round(&mut x, 3, 7, 11, 15);
+		// Even rounds
+		quarter_round(&mut x, 0, 5, 10, 15);
+		quarter_round(&mut x, 1, 6, 11, 12);
+		quarter_round(&mut x, 2, 7, 8, 13);
+		quarter_round(&mut x, 3, 4, 9, 14);
+	}
+	for (word, initial) in x.iter_mut().zip(initial.iter()) {
+		*word = _mm_add_epi32(*word, *initial);
+	}
+
+	// Transpose each group of four words, so each vector holds four words of one block.
+	let output = output.as_mut_ptr();
+	for group in 0..4 {
+		let [a, b, c, d] = [
+			x[group * 4],
+			x[group * 4 + 1],
+			x[group * 4 + 2],
+			x[group * 4 + 3],
+		];
+		let ab_low = _mm_unpacklo_epi32(a, b);
+		let cd_low = _mm_unpacklo_epi32(c, d);
+		let ab_high = _mm_unpackhi_epi32(a, b);
+		let cd_high = _mm_unpackhi_epi32(c, d);
+		let blocks = [
+			_mm_unpacklo_epi64(ab_low, cd_low),
+			_mm_unpackhi_epi64(ab_low, cd_low),
+			_mm_unpacklo_epi64(ab_high, cd_high),
+			_mm_unpackhi_epi64(ab_high, cd_high),
+		];
+		for (block, words) in blocks.iter().enumerate() {
+			_mm_storeu_si128(output.add(block * 64 + group * 16) as *mut __m128i, *words);
+		}
+	}
+}
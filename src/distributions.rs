@@ -0,0 +1,610 @@
+use crate::Rng;
+use core::fmt::{self, Display, Formatter};
+
+pub use bernoulli::Bernoulli;
+pub use binomial::Binomial;
+pub use cauchy::Cauchy;
+pub use exponential::{Exponential, StandardExponential};
+pub use gamma::{Beta, ChiSquared, Gamma, StudentT};
+pub use geometric::{Geometric, StandardGeometric};
+pub use hypergeometric::Hypergeometric;
+pub use normal::{LogNormal, Normal, StandardNormal};
+pub use pareto::Pareto;
+pub use poisson::Poisson;
+pub use triangular::Triangular;
+pub use weibull::Weibull;
+#[cfg(feature = "alloc")]
+pub use weighted::{DynamicWeightedIndex, Weight, WeightedError, WeightedIndex};
+pub use zipf::{Zeta, Zipf};
+
+/// The Bernoulli distribution.
+pub mod bernoulli;
+/// The binomial distribution.
+pub mod binomial;
+/// The Cauchy distribution.
+pub mod cauchy;
+/// The exponential distribution.
+pub mod exponential;
+/// The gamma distribution, and the distributions derived from it.
+pub mod gamma;
+/// The geometric distribution.
+pub mod geometric;
+/// The hypergeometric distribution.
+pub mod hypergeometric;
+/// The normal and log-normal distributions.
+pub mod normal;
+/// The Pareto distribution.
+pub mod pareto;
+/// The Poisson distribution.
+pub mod poisson;
+/// The triangular distribution.
+pub mod triangular;
+/// The Weibull distribution.
+pub mod weibull;
+/// Picking indices with a probability proportional to their weight.
+#[cfg(feature = "alloc")]
+pub mod weighted;
+mod ziggurat;
+/// The Zipf and Zeta distributions.
+pub mod zipf;
+
+/// A probability distribution, which can produce values of type `T` from any [`Rng`].
+///
+/// ```rust
+/// use nanorand::{distributions::Normal, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let normal = Normal::new(10.0, 2.0).unwrap();
+/// println!("Sampled from N(10, 2²): {}", rng.sample(&normal));
+/// ```
+pub trait Distribution<T> {
+	/// Samples a value from this distribution, using the provided RNG.
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> T;
+}
+
+impl<T, D: Distribution<T>> Distribution<T> for &D {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> T {
+		(*self).sample(rng)
+	}
+}
+
+/// An error returned when constructing a distribution with invalid parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DistributionError {
+	/// One of the parameters was NaN or infinite.
+	NonFinite,
+	/// One of the parameters needed to be greater than zero, but wasn't.
+	NonPositive,
+	/// One of the parameters was outside of its valid range.
+	OutOfRange,
+}
+
+impl Display for DistributionError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::NonFinite => write!(f, "parameter must be finite"),
+			Self::NonPositive => write!(f, "parameter must be greater than zero"),
+			Self::OutOfRange => write!(f, "parameter is out of range"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DistributionError {}
+
+/// Checks that a parameter is finite.
+pub(crate) fn finite(value: f64) -> Result<f64, DistributionError> {
+	if value.is_finite() {
+		Ok(value)
+	} else {
+		Err(DistributionError::NonFinite)
+	}
+}
+
+/// Checks that a parameter is finite, and greater than zero.
+pub(crate) fn positive(value: f64) -> Result<f64, DistributionError> {
+	if finite(value)? > 0.0 {
+		Ok(value)
+	} else {
+		Err(DistributionError::NonPositive)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::WyRand;
+
+	const SAMPLES: usize = 200_000;
+
+	/// Checks that the sample mean and variance of a distribution are close to what they should be.
+	fn check_moments<D: Distribution<f64>>(distribution: D, mean: f64, variance: f64) {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let samples: Vec<f64> = (0..SAMPLES).map(|_| rng.sample(&distribution)).collect();
+		let sample_mean = samples.iter().sum::<f64>() / SAMPLES as f64;
+		let sample_variance = samples
+			.iter()
+			.map(|x| (x - sample_mean).powi(2))
+			.sum::<f64>()
+			/ (SAMPLES - 1) as f64;
+		// The standard error of the mean is sqrt(variance / n), so allow for 5 of those.
+		let mean_tolerance = 5.0 * (variance / SAMPLES as f64).sqrt();
+		assert!(
+			(sample_mean - mean).abs() <= mean_tolerance,
+			"sample mean {} was too far from {}",
+			sample_mean,
+			mean
+		);
+		assert!(
+			(sample_variance - variance).abs() <= 0.05 * variance,
+			"sample variance {} was too far from {}",
+			sample_variance,
+			variance
+		);
+	}
+
+	/// Checks that a fraction of samples fall below a given quantile, for distributions without finite moments.
+	fn check_quantile<D: Distribution<f64>>(distribution: D, quantile: f64, probability: f64) {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let below = (0..SAMPLES)
+			.filter(|_| rng.sample(&distribution) < quantile)
+			.count() as f64
+			/ SAMPLES as f64;
+		assert!(
+			(below - probability).abs() < 0.005,
+			"{} of samples were below {}, expected {}",
+			below,
+			quantile,
+			probability
+		);
+	}
+
+	#[test]
+	fn ensure_normal_is_accurate() {
+		check_moments(StandardNormal, 0.0, 1.0);
+		check_moments(Normal::new(-3.0, 2.5).unwrap(), -3.0, 6.25);
+		check_quantile(StandardNormal, -1.0, 0.158_655_253_931_457);
+		check_quantile(StandardNormal, 2.0, 0.977_249_868_051_821);
+		let (mu, sigma) = (0.5_f64, 0.4_f64);
+		check_moments(
+			LogNormal::new(mu, sigma).unwrap(),
+			(mu + sigma * sigma / 2.0).exp(),
+			((sigma * sigma).exp() - 1.0) * (2.0 * mu + sigma * sigma).exp(),
+		);
+	}
+
+	#[test]
+	fn ensure_normal_tail_is_reached() {
+		let mut rng = WyRand::new_seed(42);
+		let extreme = (0..1_000_000)
+			.map(|_| rng.sample(StandardNormal))
+			.filter(|x| x.abs() > ziggurat::NORMAL_R)
+			.count();
+		// P(|Z| > 3.654) is about 2.6e-4, so we should see a couple hundred of these.
+		assert!(
+			(150..400).contains(&extreme),
+			"{} samples in the tail",
+			extreme
+		);
+	}
+
+	#[test]
+	fn ensure_exponential_is_accurate() {
+		check_moments(StandardExponential, 1.0, 1.0);
+		check_moments(Exponential::new(4.0).unwrap(), 0.25, 0.0625);
+		check_quantile(StandardExponential, 1.0, 1.0 - (-1.0_f64).exp());
+	}
+
+	#[test]
+	fn ensure_gamma_family_is_accurate() {
+		check_moments(Gamma::new(9.0, 0.5).unwrap(), 4.5, 2.25);
+		check_moments(Gamma::new(0.3, 2.0).unwrap(), 0.6, 1.2);
+		check_moments(Gamma::new(1.0, 1.0).unwrap(), 1.0, 1.0);
+		check_moments(ChiSquared::new(1.0).unwrap(), 1.0, 2.0);
+		check_moments(ChiSquared::new(7.0).unwrap(), 7.0, 14.0);
+		check_moments(Beta::new(2.0, 5.0).unwrap(), 2.0 / 7.0, 10.0 / (49.0 * 8.0));
+		check_moments(Beta::new(0.5, 0.5).unwrap(), 0.5, 0.125);
+		check_moments(StudentT::new(10.0).unwrap(), 0.0, 1.25);
+		check_quantile(StudentT::new(1.0).unwrap(), 1.0, 0.75);
+	}
+
+	#[test]
+	fn ensure_other_distributions_are_accurate() {
+		check_quantile(Cauchy::new(2.0, 3.0).unwrap(), 2.0, 0.5);
+		check_quantile(Cauchy::new(2.0, 3.0).unwrap(), 5.0, 0.75);
+		check_moments(
+			Weibull::new(2.0, 1.5).unwrap(),
+			1.805_490_585_901_867_6,
+			1.502_761_139_255_727,
+		);
+		check_moments(Pareto::new(1.0, 8.0).unwrap(), 8.0 / 7.0, 8.0 / 294.0);
+		check_moments(Triangular::new(0.0, 10.0, 2.0).unwrap(), 4.0, 84.0 / 18.0);
+		check_moments(
+			Triangular::new(-1.0, 1.0, 1.0).unwrap(),
+			1.0 / 3.0,
+			2.0 / 9.0,
+		);
+	}
+
+	#[test]
+	fn ensure_samples_stay_in_support() {
+		let mut rng = WyRand::new_seed(7);
+		let gamma = Gamma::new(0.01, 1.0).unwrap();
+		let beta = Beta::new(0.1, 0.1).unwrap();
+		let triangular = Triangular::new(1.0, 2.0, 1.0).unwrap();
+		let pareto = Pareto::new(3.0, 0.5).unwrap();
+		for _ in 0..100_000 {
+			assert!(rng.sample(gamma) >= 0.0);
+			assert!((0.0..=1.0).contains(&rng.sample(beta)));
+			assert!((1.0..=2.0).contains(&rng.sample(triangular)));
+			assert!(rng.sample(pareto) >= 3.0);
+			assert!(rng.sample(StandardExponential) > 0.0);
+		}
+	}
+
+	#[test]
+	fn ensure_invalid_parameters_are_rejected() {
+		assert_eq!(
+			Normal::new(f64::NAN, 1.0).unwrap_err(),
+			DistributionError::NonFinite
+		);
+		assert_eq!(
+			Normal::new(0.0, 0.0).unwrap_err(),
+			DistributionError::NonPositive
+		);
+		assert_eq!(
+			Exponential::new(-1.0).unwrap_err(),
+			DistributionError::NonPositive
+		);
+		assert_eq!(
+			Gamma::new(1.0, f64::INFINITY).unwrap_err(),
+			DistributionError::NonFinite
+		);
+		assert_eq!(
+			Beta::new(0.0, 1.0).unwrap_err(),
+			DistributionError::NonPositive
+		);
+		assert_eq!(
+			Weibull::new(1.0, -2.0).unwrap_err(),
+			DistributionError::NonPositive
+		);
+		assert_eq!(
+			Triangular::new(0.0, 1.0, 2.0).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Triangular::new(1.0, 1.0, 1.0).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+	}
+
+	/// `ln(n!)` for every `n` up to `max`.
+	fn ln_factorials(max: usize) -> Vec<f64> {
+		let mut table = vec![0.0; max + 1];
+		for i in 1..=max {
+			table[i] = table[i - 1] + (i as f64).ln();
+		}
+		table
+	}
+
+	/// Runs a chi-squared goodness of fit test on a discrete distribution, with values above `max` lumped together.
+	fn check_pmf<D: Distribution<u64>, Pmf: Fn(u64) -> f64>(distribution: D, pmf: Pmf, max: u64) {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let mut observed = vec![0_usize; max as usize + 2];
+		for _ in 0..SAMPLES {
+			let value = rng.sample(&distribution);
+			observed[value.min(max + 1) as usize] += 1;
+		}
+		let mut expected: Vec<f64> = (0..=max).map(|k| pmf(k) * SAMPLES as f64).collect();
+		expected.push(SAMPLES as f64 - expected.iter().sum::<f64>());
+
+		// Bins with too few expected values throw the test off, so merge those into one bin.
+		let (mut statistic, mut bins) = (0.0, 0);
+		let (mut other_observed, mut other_expected) = (0.0, 0.0);
+		for (observed, expected) in observed.into_iter().zip(expected) {
+			if expected >= 5.0 {
+				statistic += (observed as f64 - expected).powi(2) / expected;
+				bins += 1;
+			} else {
+				other_observed += observed as f64;
+				other_expected += expected;
+			}
+		}
+		if other_expected >= 1.0 {
+			statistic += (other_observed - other_expected).powi(2) / other_expected;
+			bins += 1;
+		} else {
+			assert!(
+				other_observed <= 10.0,
+				"{} samples were way out in the tails",
+				other_observed
+			);
+		}
+		// The statistic has a mean of df, and a variance of 2 * df.
+		let df = (bins - 1) as f64;
+		assert!(
+			statistic <= df + 6.0 * (2.0 * df).sqrt(),
+			"chi-squared statistic of {} with {} degrees of freedom",
+			statistic,
+			df
+		);
+	}
+
+	/// Checks the sample mean and variance of a discrete distribution, for parameters too large for [`check_pmf`].
+	fn check_discrete_moments<D: Distribution<u64>>(distribution: D, mean: f64, variance: f64) {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let samples: Vec<f64> = (0..SAMPLES)
+			.map(|_| rng.sample(&distribution) as f64)
+			.collect();
+		let sample_mean = samples.iter().sum::<f64>() / SAMPLES as f64;
+		let sample_variance = samples
+			.iter()
+			.map(|x| (x - sample_mean).powi(2))
+			.sum::<f64>()
+			/ (SAMPLES - 1) as f64;
+		assert!(
+			(sample_mean - mean).abs() <= 5.0 * (variance / SAMPLES as f64).sqrt(),
+			"sample mean {} was too far from {}",
+			sample_mean,
+			mean
+		);
+		assert!(
+			(sample_variance - variance).abs() <= 0.05 * variance,
+			"sample variance {} was too far from {}",
+			sample_variance,
+			variance
+		);
+	}
+
+	#[test]
+	fn ensure_binomial_is_accurate() {
+		let ln_factorial = ln_factorials(1000);
+		let pmf = |n: u64, p: f64| {
+			let ln_factorial = &ln_factorial;
+			move |k: u64| {
+				if k > n {
+					return 0.0;
+				}
+				let (n, k) = (n as usize, k as usize);
+				(ln_factorial[n] - ln_factorial[k] - ln_factorial[n - k]
+					+ k as f64 * p.ln()
+					+ (n - k) as f64 * (1.0 - p).ln())
+				.exp()
+			}
+		};
+		check_pmf(Binomial::new(20, 0.3).unwrap(), pmf(20, 0.3), 20);
+		check_pmf(Binomial::new(50, 0.99).unwrap(), pmf(50, 0.99), 50);
+		check_pmf(Binomial::new(1000, 0.4).unwrap(), pmf(1000, 0.4), 1000);
+		check_pmf(Binomial::new(500, 0.9).unwrap(), pmf(500, 0.9), 500);
+		check_pmf(Binomial::new(100, 0.15).unwrap(), pmf(100, 0.15), 100);
+		check_discrete_moments(
+			Binomial::new(1 << 40, 0.3).unwrap(),
+			(1_u64 << 40) as f64 * 0.3,
+			(1_u64 << 40) as f64 * 0.21,
+		);
+		let mut rng = WyRand::new_seed(0x5eed);
+		assert_eq!(rng.sample(Binomial::new(10, 0.0).unwrap()), 0);
+		assert_eq!(rng.sample(Binomial::new(10, 1.0).unwrap()), 10);
+	}
+
+	#[test]
+	fn ensure_poisson_is_accurate() {
+		let ln_factorial = ln_factorials(2000);
+		let pmf = |lambda: f64| {
+			let ln_factorial = &ln_factorial;
+			move |k: u64| (-lambda + k as f64 * lambda.ln() - ln_factorial[k as usize]).exp()
+		};
+		check_pmf(Poisson::new(0.5).unwrap(), pmf(0.5), 20);
+		check_pmf(Poisson::new(3.5).unwrap(), pmf(3.5), 40);
+		check_pmf(Poisson::new(10.0).unwrap(), pmf(10.0), 60);
+		check_pmf(Poisson::new(123.4).unwrap(), pmf(123.4), 300);
+		check_pmf(Poisson::new(1000.0).unwrap(), pmf(1000.0), 2000);
+		check_discrete_moments(Poisson::new(1e12).unwrap(), 1e12, 1e12);
+	}
+
+	#[test]
+	fn ensure_geometric_is_accurate() {
+		let pmf = |p: f64| move |k: u64| p * (1.0 - p).powi(k as i32);
+		check_pmf(StandardGeometric, pmf(0.5), 40);
+		check_pmf(Geometric::new(0.5).unwrap(), pmf(0.5), 40);
+		check_pmf(Geometric::new(0.8).unwrap(), pmf(0.8), 20);
+		check_pmf(Geometric::new(0.3).unwrap(), pmf(0.3), 60);
+		check_pmf(Geometric::new(0.01).unwrap(), pmf(0.01), 1000);
+		check_discrete_moments(Geometric::new(1e-12).unwrap(), 1e12, 1e24);
+		let mut rng = WyRand::new_seed(0x5eed);
+		assert_eq!(rng.sample(Geometric::new(1.0).unwrap()), 0);
+	}
+
+	#[test]
+	fn ensure_hypergeometric_is_accurate() {
+		let ln_factorial = ln_factorials(1000);
+		let pmf = |population: u64, successes: u64, draws: u64| {
+			let ln_factorial = &ln_factorial;
+			let ln_choose = move |n: u64, k: u64| {
+				ln_factorial[n as usize] - ln_factorial[k as usize] - ln_factorial[(n - k) as usize]
+			};
+			move |k: u64| {
+				if k > successes || k > draws || draws - k > population - successes {
+					return 0.0;
+				}
+				(ln_choose(successes, k) + ln_choose(population - successes, draws - k)
+					- ln_choose(population, draws))
+				.exp()
+			}
+		};
+		check_pmf(Hypergeometric::new(52, 4, 5).unwrap(), pmf(52, 4, 5), 5);
+		check_pmf(
+			Hypergeometric::new(52, 40, 45).unwrap(),
+			pmf(52, 40, 45),
+			45,
+		);
+		check_pmf(
+			Hypergeometric::new(100, 30, 60).unwrap(),
+			pmf(100, 30, 60),
+			60,
+		);
+		check_pmf(
+			Hypergeometric::new(1000, 600, 700).unwrap(),
+			pmf(1000, 600, 700),
+			700,
+		);
+		check_pmf(
+			Hypergeometric::new(1000, 300, 200).unwrap(),
+			pmf(1000, 300, 200),
+			200,
+		);
+		check_pmf(
+			Hypergeometric::new(1000, 100, 900).unwrap(),
+			pmf(1000, 100, 900),
+			100,
+		);
+		let (n, k, draws) = (1e12, 3e11, 4e11);
+		check_discrete_moments(
+			Hypergeometric::new(n as u64, k as u64, draws as u64).unwrap(),
+			draws * k / n,
+			draws * (k / n) * (1.0 - k / n) * (n - draws) / (n - 1.0),
+		);
+		let mut rng = WyRand::new_seed(0x5eed);
+		assert_eq!(rng.sample(Hypergeometric::new(10, 10, 4).unwrap()), 4);
+		assert_eq!(rng.sample(Hypergeometric::new(10, 3, 10).unwrap()), 3);
+		assert_eq!(rng.sample(Hypergeometric::new(10, 0, 5).unwrap()), 0);
+	}
+
+	#[test]
+	fn ensure_zipf_is_accurate() {
+		let pmf = |n: u64, s: f64| {
+			let total: f64 = (1..=n).map(|k| (k as f64).powf(-s)).sum();
+			move |k: u64| {
+				if k == 0 || k > n {
+					0.0
+				} else {
+					(k as f64).powf(-s) / total
+				}
+			}
+		};
+		check_pmf(Zipf::new(1, 1.0).unwrap(), pmf(1, 1.0), 1);
+		check_pmf(Zipf::new(10, 1.0).unwrap(), pmf(10, 1.0), 10);
+		check_pmf(Zipf::new(100, 2.5).unwrap(), pmf(100, 2.5), 100);
+		check_pmf(Zipf::new(1000, 0.5).unwrap(), pmf(1000, 0.5), 1000);
+		check_pmf(Zipf::new(50, 0.0).unwrap(), pmf(50, 0.0), 50);
+		// The tail past 100,000 is negligible, so this approximates the infinite sum.
+		check_pmf(Zipf::new(u64::MAX, 3.0).unwrap(), pmf(100_000, 3.0), 100);
+
+		const ZETA_3: f64 = 1.202_056_903_159_594_2;
+		check_pmf(
+			Zeta::new(3.0).unwrap(),
+			|k| {
+				if k == 0 {
+					0.0
+				} else {
+					(k as f64).powi(-3) / ZETA_3
+				}
+			},
+			100,
+		);
+	}
+
+	#[test]
+	fn ensure_bernoulli_is_accurate() {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let trials = 200_000;
+		let check = |successes: usize, p: f64| {
+			// Allow for 5 standard deviations of the binomial distribution.
+			let expected = trials as f64 * p;
+			let tolerance = 5.0 * (expected * (1.0 - p)).sqrt();
+			assert!(
+				(successes as f64 - expected).abs() <= tolerance,
+				"{} successes was too far from {}",
+				successes,
+				expected
+			);
+		};
+		for p in [0.001, 0.3, 0.5, 0.9] {
+			let bernoulli = Bernoulli::new(p).unwrap();
+			check((0..trials).filter(|_| rng.sample(bernoulli)).count(), p);
+			check((0..trials).filter(|_| rng.gen_bool(p)).count(), p);
+		}
+		for (numerator, denominator) in [(1, 3), (3, 4), (2, 7), (999_999, 1_000_000)] {
+			let p = numerator as f64 / denominator as f64;
+			let bernoulli = Bernoulli::from_ratio(numerator, denominator).unwrap();
+			check((0..trials).filter(|_| rng.sample(bernoulli)).count(), p);
+			check(
+				(0..trials)
+					.filter(|_| rng.gen_ratio(numerator, denominator))
+					.count(),
+				p,
+			);
+		}
+
+		// The extremes should never be wrong.
+		for _ in 0..1_000 {
+			assert!(rng.gen_bool(1.0));
+			assert!(!rng.gen_bool(0.0));
+			assert!(rng.gen_ratio(5, 5));
+			assert!(!rng.gen_ratio(0, 5));
+			assert!(rng.sample(Bernoulli::from_ratio(1, 1).unwrap()));
+			assert!(!rng.sample(Bernoulli::from_ratio(0, 1).unwrap()));
+		}
+		// Thresholds should be exact.
+		assert_eq!(Bernoulli::new(0.5), Bernoulli::from_ratio(1, 2));
+		assert_eq!(Bernoulli::new(0.75), Bernoulli::from_ratio(3, 4));
+		assert_eq!(
+			Bernoulli::new(0.5f64.powi(63)),
+			Bernoulli::from_ratio(1, 1 << 63)
+		);
+		assert_ne!(Bernoulli::new(0.5f64.powi(64)), Bernoulli::new(0.0));
+	}
+
+	#[test]
+	fn ensure_invalid_discrete_parameters_are_rejected() {
+		assert_eq!(
+			Binomial::new(10, 1.5).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Binomial::new(10, f64::NAN).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Poisson::new(0.0).unwrap_err(),
+			DistributionError::NonPositive
+		);
+		assert_eq!(
+			Geometric::new(0.0).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Hypergeometric::new(10, 11, 5).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Hypergeometric::new(10, 5, 11).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Zipf::new(0, 1.0).unwrap_err(),
+			DistributionError::NonPositive
+		);
+		assert_eq!(
+			Zipf::new(10, -1.0).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(Zeta::new(1.0).unwrap_err(), DistributionError::OutOfRange);
+		assert_eq!(
+			Bernoulli::new(1.5).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Bernoulli::new(f64::NAN).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Bernoulli::from_ratio(1, 0).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+		assert_eq!(
+			Bernoulli::from_ratio(4, 3).unwrap_err(),
+			DistributionError::OutOfRange
+		);
+	}
+}
@@ -0,0 +1,106 @@
+use super::{Distribution, DistributionError};
+use crate::Rng;
+
+/// The Bernoulli distribution, which is `true` with probability `p`, and `false` otherwise.
+///
+/// The probability is converted into a threshold ahead of time, so sampling only needs one random [`u64`]
+/// and a comparison.
+///
+/// ```rust
+/// use nanorand::{distributions::Bernoulli, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let rollout = Bernoulli::new(0.05).unwrap();
+/// if rng.sample(&rollout) {
+///     println!("You got the new feature!");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bernoulli {
+	method: Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Method {
+	/// `true` when a random [`u64`] is below `threshold`, which is `p * 2^64`.
+	Threshold(u64),
+	/// `p == 1`, which doesn't fit into a threshold.
+	Always,
+	/// `true` when a random index in `0..denominator` is below `numerator`.
+	Ratio {
+		numerator: u64,
+		denominator: u64,
+		/// `2^64 % denominator`, as anything left over below it would bias the index.
+		rejection: u64,
+	},
+}
+
+impl Bernoulli {
+	/// Creates a new Bernoulli distribution, which is `true` with probability `p`.
+	///
+	/// Every probability is handled exactly, down to the precision of an [`f64`], or 2^-64, whichever is coarser.
+	///
+	/// # Errors
+	/// This function will return an error if `p` isn't within `[0, 1]`.
+	pub fn new(p: f64) -> Result<Self, DistributionError> {
+		if !(0.0..=1.0).contains(&p) {
+			return Err(DistributionError::OutOfRange);
+		}
+		let method = if p == 1.0 {
+			Method::Always
+		} else {
+			// Multiplying by 2^64 only changes the exponent, so this is exact,
+			// and any bits below 2^-64 are beyond what a u64 can decide anyway.
+			Method::Threshold((p * 18_446_744_073_709_551_616.0) as u64)
+		};
+		Ok(Self { method })
+	}
+
+	/// Creates a new Bernoulli distribution, which is `true` with probability `numerator / denominator`.
+	///
+	/// Unlike [`Bernoulli::new`], there's no rounding, so this is exact for any ratio.
+	///
+	/// # Errors
+	/// This function will return an error if `denominator` is zero, or `numerator` is greater than `denominator`.
+	pub const fn from_ratio(numerator: u64, denominator: u64) -> Result<Self, DistributionError> {
+		if denominator == 0 || numerator > denominator {
+			return Err(DistributionError::OutOfRange);
+		}
+		let method = if numerator == denominator {
+			Method::Always
+		} else if numerator == 0 {
+			Method::Threshold(0)
+		} else if denominator.is_power_of_two() {
+			// Powers of two divide 2^64 evenly, so the threshold is exact.
+			Method::Threshold(numerator << (64 - denominator.trailing_zeros()))
+		} else {
+			Method::Ratio {
+				numerator,
+				denominator,
+				rejection: denominator.wrapping_neg() % denominator,
+			}
+		};
+		Ok(Self { method })
+	}
+}
+
+impl Distribution<bool> for Bernoulli {
+	#[inline]
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> bool {
+		match self.method {
+			Method::Threshold(threshold) => rng.generate::<u64>() < threshold,
+			Method::Always => true,
+			Method::Ratio {
+				numerator,
+				denominator,
+				rejection,
+			} => loop {
+				// Lemire's method, with the division done ahead of time.
+				let multiplied = u128::from(rng.generate::<u64>()) * u128::from(denominator);
+				if multiplied as u64 >= rejection {
+					return ((multiplied >> 64) as u64) < numerator;
+				}
+			},
+		}
+	}
+}
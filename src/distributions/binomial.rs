@@ -0,0 +1,223 @@
+use super::{Distribution, DistributionError};
+use crate::{math, Rng};
+
+/// The binomial distribution, `Binomial(n, p)`, which counts the successes in `n` independent trials
+/// that each succeed with probability `p`.
+///
+/// Small means are sampled exactly by inversion, and larger ones with the BTPE algorithm from
+/// Kachitvichyanukul & Schmeiser's "Binomial Random Variate Generation".
+///
+/// ```rust
+/// use nanorand::{distributions::Binomial, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let heads = rng.sample(Binomial::new(100, 0.5).unwrap());
+/// assert!(heads <= 100);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+	n: u64,
+	/// Whether we're actually sampling the failures, as `p` was above 0.5.
+	flipped: bool,
+	method: Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Method {
+	Constant(u64),
+	Inversion {
+		/// `p / q`
+		s: f64,
+		/// `(n + 1) * p / q`
+		a: f64,
+		/// `P(X = 0)`, or `q^n`
+		r: f64,
+	},
+	Btpe(Btpe),
+}
+
+/// The precomputed constants for BTPE, named as they are in the paper.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Btpe {
+	p: f64,
+	q: f64,
+	npq: f64,
+	m: f64,
+	x_m: f64,
+	x_l: f64,
+	x_r: f64,
+	c: f64,
+	lambda_l: f64,
+	lambda_r: f64,
+	p1: f64,
+	p2: f64,
+	p3: f64,
+	p4: f64,
+	/// `ln(f(m))`, the log-probability of the mode.
+	ln_f_m: f64,
+}
+
+impl Binomial {
+	/// Creates a new binomial distribution, with `n` trials which each succeed with probability `p`.
+	///
+	/// # Errors
+	/// This function will return an error if `p` isn't within `[0, 1]`.
+	pub fn new(n: u64, p: f64) -> Result<Self, DistributionError> {
+		if !(0.0..=1.0).contains(&p) {
+			return Err(DistributionError::OutOfRange);
+		}
+		let flipped = p > 0.5;
+		let (p, q) = if flipped { (1.0 - p, p) } else { (p, 1.0 - p) };
+		let float_n = n as f64;
+		let method = if p == 0.0 {
+			Method::Constant(0)
+		} else if float_n * p < 10.0 {
+			Method::Inversion {
+				s: p / q,
+				a: (float_n + 1.0) * p / q,
+				r: math::exp(float_n * math::ln_1p(-p)),
+			}
+		} else {
+			let np = float_n * p;
+			let npq = np * q;
+			let m = math::floor(np + p);
+			let p1 = math::floor(2.195 * math::sqrt(npq) - 4.6 * q) + 0.5;
+			let x_m = m + 0.5;
+			let x_l = x_m - p1;
+			let x_r = x_m + p1;
+			let c = 0.134 + 20.5 / (15.3 + m);
+			let lambda = |a: f64| a * (1.0 + 0.5 * a);
+			let lambda_l = lambda((np + p - x_l) / (np + p - x_l * p));
+			let lambda_r = lambda((x_r - np - p) / (x_r * q));
+			let p2 = p1 * (1.0 + 2.0 * c);
+			let p3 = p2 + c / lambda_l;
+			let p4 = p3 + c / lambda_r;
+			Method::Btpe(Btpe {
+				p,
+				q,
+				npq,
+				m,
+				x_m,
+				x_l,
+				x_r,
+				c,
+				lambda_l,
+				lambda_r,
+				p1,
+				p2,
+				p3,
+				p4,
+				ln_f_m: math::ln_binomial_pmf(m, float_n, p, q),
+			})
+		};
+		Ok(Self { n, flipped, method })
+	}
+}
+
+impl Btpe {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(
+		&self,
+		rng: &mut Generator,
+		n: f64,
+	) -> f64 {
+		loop {
+			// Step 1: pick a region. The triangle in the middle is accepted immediately.
+			let u = rng.generate::<f64>() * self.p4;
+			let mut v = rng.generate::<f64>();
+			if u <= self.p1 {
+				return math::floor(self.x_m - self.p1 * v + u);
+			}
+			let y = if u <= self.p2 {
+				// Step 2: the parallelograms.
+				let x = self.x_l + (u - self.p1) / self.c;
+				v = v * self.c + 1.0 - math::abs(x - self.x_m) / self.p1;
+				if v > 1.0 {
+					continue;
+				}
+				math::floor(x)
+			} else if u <= self.p3 {
+				// Step 3: the left exponential tail.
+				let y = math::floor(self.x_l + math::ln(v) / self.lambda_l);
+				if y < 0.0 {
+					continue;
+				}
+				v *= (u - self.p2) * self.lambda_l;
+				y
+			} else {
+				// Step 4: the right exponential tail.
+				let y = math::floor(self.x_r - math::ln(v) / self.lambda_r);
+				if y > n {
+					continue;
+				}
+				v *= (u - self.p3) * self.lambda_r;
+				y
+			};
+
+			// Step 5: accept if v <= f(y) / f(m).
+			let k = math::abs(y - self.m);
+			if k <= 20.0 || k >= 0.5 * self.npq - 1.0 {
+				// Close to the mode, f(y) / f(m) can be evaluated exactly with the recurrence relation.
+				let s = self.p / self.q;
+				let a = s * (n + 1.0);
+				let mut f = 1.0;
+				let mut i = self.m;
+				while i < y {
+					i += 1.0;
+					f *= a / i - s;
+				}
+				while i > y {
+					f /= a / i - s;
+					i -= 1.0;
+				}
+				if v <= f {
+					return y;
+				}
+				continue;
+			}
+
+			// Otherwise, squeeze ln(v) between the bounds of ln(f(y) / f(m)) first.
+			let rho = (k / self.npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / self.npq + 0.5);
+			let t = -0.5 * k * k / self.npq;
+			let alpha = math::ln(v);
+			if alpha < t - rho {
+				return y;
+			}
+			if alpha > t + rho {
+				continue;
+			}
+			if alpha <= math::ln_binomial_pmf(y, n, self.p, self.q) - self.ln_f_m {
+				return y;
+			}
+		}
+	}
+}
+
+impl Distribution<u64> for Binomial {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> u64 {
+		let successes = match &self.method {
+			Method::Constant(successes) => *successes,
+			Method::Inversion { s, a, r } => 'outer: loop {
+				// Walk up the CDF, until we've passed a uniform random number.
+				let mut u = rng.generate::<f64>();
+				let mut r = *r;
+				let mut x = 0;
+				while u > r {
+					u -= r;
+					x += 1;
+					if x > self.n {
+						// We ran off the end due to rounding error, so try again.
+						continue 'outer;
+					}
+					r *= a / x as f64 - s;
+				}
+				break x;
+			},
+			Method::Btpe(btpe) => btpe.sample(rng, self.n as f64) as u64,
+		};
+		if self.flipped {
+			self.n - successes
+		} else {
+			successes
+		}
+	}
+}
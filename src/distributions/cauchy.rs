@@ -0,0 +1,37 @@
+use super::{finite, positive, Distribution, DistributionError, StandardNormal};
+use crate::Rng;
+
+/// The Cauchy (or Lorentz) distribution, with the given median and scale.
+///
+/// As `core` has no `tan`, this is sampled as the ratio of two standard normal variables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cauchy {
+	median: f64,
+	scale: f64,
+}
+
+impl Cauchy {
+	/// Creates a new Cauchy distribution, with the given median (`x0`) and scale (`gamma`).
+	///
+	/// # Errors
+	/// This function will return an error if either parameter isn't finite,
+	/// or if `scale` isn't greater than zero.
+	pub fn new(median: f64, scale: f64) -> Result<Self, DistributionError> {
+		Ok(Self {
+			median: finite(median)?,
+			scale: positive(scale)?,
+		})
+	}
+}
+
+impl Distribution<f64> for Cauchy {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		loop {
+			let numerator: f64 = StandardNormal.sample(rng);
+			let denominator: f64 = StandardNormal.sample(rng);
+			if denominator != 0.0 {
+				return self.median + self.scale * numerator / denominator;
+			}
+		}
+	}
+}
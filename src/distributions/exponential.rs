@@ -0,0 +1,43 @@
+use super::{positive, ziggurat, Distribution, DistributionError};
+use crate::{math, Rng};
+
+/// The standard exponential distribution, `Exp(1)`, sampled using the ziggurat method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StandardExponential;
+
+impl Distribution<f64> for StandardExponential {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		ziggurat::ziggurat(
+			rng,
+			false,
+			&ziggurat::EXPONENTIAL_X,
+			&ziggurat::EXPONENTIAL_F,
+			|x| math::exp(-x),
+			ziggurat::exponential_tail,
+		)
+	}
+}
+
+/// The exponential distribution, `Exp(lambda)`, with a mean of `1 / lambda`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exponential {
+	inverse_lambda: f64,
+}
+
+impl Exponential {
+	/// Creates a new exponential distribution, with the given rate.
+	///
+	/// # Errors
+	/// This function will return an error if `lambda` isn't finite, or isn't greater than zero.
+	pub fn new(lambda: f64) -> Result<Self, DistributionError> {
+		Ok(Self {
+			inverse_lambda: 1.0 / positive(lambda)?,
+		})
+	}
+}
+
+impl Distribution<f64> for Exponential {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		StandardExponential.sample(rng) * self.inverse_lambda
+	}
+}
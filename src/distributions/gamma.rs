@@ -0,0 +1,172 @@
+use super::{positive, Distribution, DistributionError, StandardNormal};
+use crate::{gen::OpenClosed01, math, Rng};
+
+/// The gamma distribution, `Gamma(shape, scale)`, sampled using Marsaglia & Tsang's method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gamma {
+	scale: f64,
+	d: f64,
+	c: f64,
+	/// For shapes below 1, we sample from `Gamma(shape + 1)` and multiply by `U^(1 / shape)`.
+	inverse_shape: Option<f64>,
+}
+
+impl Gamma {
+	/// Creates a new gamma distribution, with the given shape (`k`) and scale (`theta`).
+	///
+	/// # Errors
+	/// This function will return an error if either parameter isn't finite, or isn't greater than zero.
+	pub fn new(shape: f64, scale: f64) -> Result<Self, DistributionError> {
+		let shape = positive(shape)?;
+		let scale = positive(scale)?;
+		let (boosted, inverse_shape) = if shape < 1.0 {
+			(shape + 1.0, Some(1.0 / shape))
+		} else {
+			(shape, None)
+		};
+		let d = boosted - 1.0 / 3.0;
+		Ok(Self {
+			scale,
+			d,
+			c: 1.0 / math::sqrt(9.0 * d),
+			inverse_shape,
+		})
+	}
+}
+
+impl Distribution<f64> for Gamma {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		let value = loop {
+			let x: f64 = StandardNormal.sample(rng);
+			let v = 1.0 + self.c * x;
+			if v <= 0.0 {
+				continue;
+			}
+			let v = v * v * v;
+			let u = rng.generate::<OpenClosed01<f64>>().0;
+			let x_squared = x * x;
+			// The squeeze lets us skip the logarithms most of the time.
+			if u < 1.0 - 0.0331 * x_squared * x_squared
+				|| math::ln(u) < 0.5 * x_squared + self.d * (1.0 - v + math::ln(v))
+			{
+				break self.d * v;
+			}
+		};
+		match self.inverse_shape {
+			Some(inverse_shape) => {
+				let u = rng.generate::<OpenClosed01<f64>>().0;
+				value * math::powf(u, inverse_shape) * self.scale
+			}
+			None => value * self.scale,
+		}
+	}
+}
+
+/// The chi-squared distribution, `χ²(k)`, which is the sum of `k` squared standard normal variables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquared {
+	/// With one degree of freedom, squaring a normal variable is both faster and more accurate.
+	gamma: Option<Gamma>,
+}
+
+impl ChiSquared {
+	/// Creates a new chi-squared distribution, with the given degrees of freedom.
+	///
+	/// # Errors
+	/// This function will return an error if `k` isn't finite, or isn't greater than zero.
+	pub fn new(k: f64) -> Result<Self, DistributionError> {
+		let gamma = if positive(k)? == 1.0 {
+			None
+		} else {
+			Some(Gamma::new(k / 2.0, 2.0)?)
+		};
+		Ok(Self { gamma })
+	}
+}
+
+impl Distribution<f64> for ChiSquared {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		match &self.gamma {
+			Some(gamma) => gamma.sample(rng),
+			None => {
+				let x: f64 = StandardNormal.sample(rng);
+				x * x
+			}
+		}
+	}
+}
+
+/// The beta distribution, `Beta(alpha, beta)`, which produces values in `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta {
+	alpha: f64,
+	beta: f64,
+	gammas: (Gamma, Gamma),
+}
+
+impl Beta {
+	/// Creates a new beta distribution, with the given shape parameters.
+	///
+	/// # Errors
+	/// This function will return an error if either parameter isn't finite, or isn't greater than zero.
+	pub fn new(alpha: f64, beta: f64) -> Result<Self, DistributionError> {
+		Ok(Self {
+			alpha,
+			beta,
+			gammas: (Gamma::new(alpha, 1.0)?, Gamma::new(beta, 1.0)?),
+		})
+	}
+}
+
+impl Distribution<f64> for Beta {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		if self.alpha > 1.0 || self.beta > 1.0 {
+			let x = self.gammas.0.sample(rng);
+			let y = self.gammas.1.sample(rng);
+			return x / (x + y);
+		}
+		// When both parameters are small, the gamma variables can both underflow to zero,
+		// so use Jöhnk's algorithm instead, in log space.
+		loop {
+			let log_x = math::ln(rng.generate::<OpenClosed01<f64>>().0) / self.alpha;
+			let log_y = math::ln(rng.generate::<OpenClosed01<f64>>().0) / self.beta;
+			let x = math::exp(log_x);
+			let y = math::exp(log_y);
+			if x + y <= 1.0 {
+				if x + y > 0.0 {
+					return x / (x + y);
+				}
+				let log_max = if log_x > log_y { log_x } else { log_y };
+				let (log_x, log_y) = (log_x - log_max, log_y - log_max);
+				return math::exp(log_x - math::ln(math::exp(log_x) + math::exp(log_y)));
+			}
+		}
+	}
+}
+
+/// Student's t-distribution, with `n` degrees of freedom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+	chi_squared: ChiSquared,
+	n: f64,
+}
+
+impl StudentT {
+	/// Creates a new t-distribution, with the given degrees of freedom.
+	///
+	/// # Errors
+	/// This function will return an error if `n` isn't finite, or isn't greater than zero.
+	pub fn new(n: f64) -> Result<Self, DistributionError> {
+		Ok(Self {
+			chi_squared: ChiSquared::new(n)?,
+			n,
+		})
+	}
+}
+
+impl Distribution<f64> for StudentT {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		let z: f64 = StandardNormal.sample(rng);
+		z * math::sqrt(self.n / self.chi_squared.sample(rng))
+	}
+}
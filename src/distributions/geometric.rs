@@ -0,0 +1,132 @@
+use super::{Distribution, DistributionError};
+use crate::{gen::OpenClosed01, math, Rng};
+
+/// The standard geometric distribution, which counts the failures before the first success
+/// in a series of trials that each succeed with probability `0.5`.
+///
+/// This is sampled by counting the leading zeros of random numbers, so it's both exact and very fast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StandardGeometric;
+
+impl Distribution<u64> for StandardGeometric {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> u64 {
+		// Each bit is a fair coin flip, so the number of zeros before the first one is what we want.
+		let mut failures = 0_u64;
+		loop {
+			let zeros = u64::from(rng.generate::<u64>().leading_zeros());
+			failures = failures.saturating_add(zeros);
+			if zeros < 64 {
+				return failures;
+			}
+		}
+	}
+}
+
+/// The geometric distribution, `Geometric(p)`, which counts the failures before the first success
+/// in a series of trials that each succeed with probability `p`.
+///
+/// ```rust
+/// use nanorand::{distributions::Geometric, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let misses = rng.sample(Geometric::new(0.2).unwrap());
+/// println!("Missed {} times before hitting", misses);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometric {
+	method: Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Method {
+	/// `p == 0.5`, so we can count bits directly.
+	Bits,
+	/// `p` is high enough that simulating each trial is the fastest approach.
+	Trials { p: f64 },
+	/// The result is split into `high << bits | low`, where the high part is the number of failed blocks of
+	/// `2^bits` trials, and the low part is sampled by rejection from the `bits` low bits of a random number.
+	Blocks {
+		/// The probability of an entire block of `2^bits` trials failing, `(1 - p)^(2^bits)`.
+		block_failure: f64,
+		bits: u32,
+		/// `ln(1 - p)`
+		ln_q: f64,
+	},
+	/// `p` is so small that a block would overflow, so use inversion.
+	Inversion {
+		/// `ln(1 - p)`
+		ln_q: f64,
+	},
+}
+
+impl Geometric {
+	/// Creates a new geometric distribution, where each trial succeeds with probability `p`.
+	///
+	/// # Errors
+	/// This function will return an error if `p` isn't within `(0, 1]`.
+	pub fn new(p: f64) -> Result<Self, DistributionError> {
+		if !(p > 0.0 && p <= 1.0) {
+			return Err(DistributionError::OutOfRange);
+		}
+		let ln_q = math::ln_1p(-p);
+		let method = if p == 0.5 {
+			Method::Bits
+		} else if p >= 2.0 / 3.0 {
+			Method::Trials { p }
+		} else {
+			// Pick the smallest block size where a block fails at most half of the time.
+			let mut bits = 1;
+			while bits < 32 && ((1_u64 << bits) as f64) * ln_q > -core::f64::consts::LN_2 {
+				bits += 1;
+			}
+			if bits < 32 {
+				Method::Blocks {
+					block_failure: math::exp(((1_u64 << bits) as f64) * ln_q),
+					bits,
+					ln_q,
+				}
+			} else {
+				Method::Inversion { ln_q }
+			}
+		};
+		Ok(Self { method })
+	}
+}
+
+impl Distribution<u64> for Geometric {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> u64 {
+		match self.method {
+			Method::Bits => StandardGeometric.sample(rng),
+			Method::Trials { p } => {
+				let mut failures = 0;
+				while rng.generate::<f64>() >= p {
+					failures += 1;
+				}
+				failures
+			}
+			Method::Blocks {
+				block_failure,
+				bits,
+				ln_q,
+			} => {
+				let mut high = 0_u64;
+				while rng.generate::<f64>() < block_failure {
+					high += 1;
+				}
+				// Within a block, P(low = m) is proportional to (1 - p)^m.
+				let low = loop {
+					let low = rng.generate::<u64>() & ((1 << bits) - 1);
+					if rng.generate::<f64>() < math::exp(low as f64 * ln_q) {
+						break low;
+					}
+				};
+				high.saturating_mul(1 << bits).saturating_add(low)
+			}
+			Method::Inversion { ln_q } => {
+				let u = rng.generate::<OpenClosed01<f64>>().0;
+				// This saturates on overflow, which is what we want.
+				math::floor(math::ln(u) / ln_q) as u64
+			}
+		}
+	}
+}
@@ -0,0 +1,194 @@
+use super::{Distribution, DistributionError};
+use crate::{math, Rng};
+
+/// The hypergeometric distribution, which counts the successes when drawing `draws` items
+/// without replacement from a population of `population` items, of which `successes` are successes.
+///
+/// Distributions with a small mode are sampled exactly by inversion, and larger ones with the HRUA
+/// ratio-of-uniforms algorithm from Stadlober's "The ratio of uniforms approach for generating discrete random variates".
+///
+/// ```rust
+/// use nanorand::{distributions::Hypergeometric, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// // How many aces are in a 5 card hand?
+/// let aces = rng.sample(Hypergeometric::new(52, 4, 5).unwrap());
+/// assert!(aces <= 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hypergeometric {
+	/// The size of the smaller of the two groups.
+	n1: u64,
+	/// The size of the larger of the two groups.
+	n2: u64,
+	/// The number of draws, or non-draws, whichever is smaller.
+	k: u64,
+	/// Whether the successes are the larger group, so we need to count the other group.
+	flip_groups: bool,
+	/// Whether we're drawing the items that will be left behind.
+	flip_draws: bool,
+	successes: u64,
+	method: Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Method {
+	Inversion {
+		/// `P(X = 0)`
+		p0: f64,
+	},
+	Hrua {
+		a: f64,
+		h: f64,
+		/// The (exclusive) upper bound of the values we'll consider.
+		b: f64,
+		/// `ln(P(X = mode))`
+		ln_f_m: f64,
+	},
+}
+
+impl Hypergeometric {
+	/// Creates a new hypergeometric distribution.
+	///
+	/// # Errors
+	/// This function will return an error if `successes` or `draws` is larger than `population`.
+	pub fn new(population: u64, successes: u64, draws: u64) -> Result<Self, DistributionError> {
+		if successes > population || draws > population {
+			return Err(DistributionError::OutOfRange);
+		}
+		// The distribution is symmetric in a few ways, so we only need to handle the cases
+		// where there are fewer successes than failures, and we draw at most half of the population.
+		let failures = population - successes;
+		let flip_groups = successes > failures;
+		let (n1, n2) = if flip_groups {
+			(failures, successes)
+		} else {
+			(successes, failures)
+		};
+		let flip_draws = draws > population - draws;
+		let k = if flip_draws {
+			population - draws
+		} else {
+			draws
+		};
+
+		let mut this = Self {
+			n1,
+			n2,
+			k,
+			flip_groups,
+			flip_draws,
+			successes,
+			method: Method::Inversion { p0: 0.0 },
+		};
+		let (n, n1, k) = (population as f64, n1 as f64, k as f64);
+		let mode = math::floor((k + 1.0) * (n1 + 1.0) / (n + 2.0));
+		this.method = if mode < 10.0 {
+			Method::Inversion {
+				p0: math::exp(this.ln_pmf(0.0)),
+			}
+		} else {
+			// Constants from Stadlober's paper, 2 * sqrt(2 / e) and 3 - 2 * sqrt(3 / e).
+			const D1: f64 = 1.715_527_769_921_413_5;
+			const D2: f64 = 0.898_916_162_058_898_8;
+			let p = n1 / n;
+			let mean = k * p;
+			let variance = (n - k) * k * p * (1.0 - p) / (n - 1.0);
+			let c = math::sqrt(variance + 0.5);
+			let a = mean + 0.5;
+			let b = math::floor(a + 16.0 * c);
+			Method::Hrua {
+				a,
+				h: D1 * c + D2,
+				b: if b < k.min(n1) + 1.0 {
+					b
+				} else {
+					k.min(n1) + 1.0
+				},
+				ln_f_m: this.ln_pmf(mode),
+			}
+		};
+		Ok(this)
+	}
+
+	/// `ln(P(X = x))`, where `X` counts the items from the smaller group, in the smaller number of draws.
+	fn ln_pmf(&self, x: f64) -> f64 {
+		// This is the ratio of binomial probabilities used by R's `dhyper`, which avoids cancellation.
+		let (n1, n2, k) = (self.n1 as f64, self.n2 as f64, self.k as f64);
+		let n = n1 + n2;
+		let p = k / n;
+		let q = (n - k) / n;
+		math::ln_binomial_pmf(x, n1, p, q) + math::ln_binomial_pmf(k - x, n2, p, q)
+			- math::ln_binomial_pmf(k, n, p, q)
+	}
+}
+
+impl Distribution<u64> for Hypergeometric {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> u64 {
+		let (n1, n2, k) = (self.n1 as f64, self.n2 as f64, self.k as f64);
+		let max = if self.k < self.n1 { self.k } else { self.n1 };
+		let x = match self.method {
+			Method::Inversion { p0 } => 'outer: loop {
+				// Walk up the CDF, until we've passed a uniform random number.
+				let mut u = rng.generate::<f64>();
+				let mut p = p0;
+				let mut x = 0;
+				while u > p {
+					u -= p;
+					if x >= max {
+						// We ran off the end due to rounding error, so try again.
+						continue 'outer;
+					}
+					let float_x = x as f64;
+					p *= (n1 - float_x) * (k - float_x)
+						/ ((float_x + 1.0) * (n2 - k + float_x + 1.0));
+					x += 1;
+				}
+				break x;
+			},
+			Method::Hrua { a, h, b, ln_f_m } => loop {
+				let u = rng.generate::<f64>();
+				let v = rng.generate::<f64>();
+				let x = a + h * (v - 0.5) / u;
+				if !(0.0..b).contains(&x) {
+					continue;
+				}
+				let x = math::floor(x);
+				let t = self.ln_pmf(x) - ln_f_m;
+				// Quick acceptance and rejection, using bounds of ln(u).
+				if u * (4.0 - u) - 3.0 <= t {
+					break x as u64;
+				}
+				if u * (u - t) >= 1.0 {
+					continue;
+				}
+				if 2.0 * math::ln(u) <= t {
+					break x as u64;
+				}
+			},
+		};
+		// Undo the symmetries we used when setting up the distribution.
+		let x = if self.flip_groups { self.k - x } else { x };
+		if self.flip_draws {
+			self.successes - x
+		} else {
+			x
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ensure_empty_groups_are_finite() {
+		for (population, successes, draws) in [(100, 0, 5), (100, 100, 5), (100, 0, 0)] {
+			let distribution = Hypergeometric::new(population, successes, draws).unwrap();
+			match distribution.method {
+				Method::Inversion { p0 } => assert_eq!(p0, 1.0, "{:?}", distribution),
+				Method::Hrua { .. } => panic!("{:?} should use inversion", distribution),
+			}
+		}
+	}
+}
@@ -0,0 +1,79 @@
+use super::{finite, positive, ziggurat, Distribution, DistributionError};
+use crate::{math, Rng};
+
+/// The standard normal distribution, `N(0, 1)`, sampled using the ziggurat method.
+///
+/// ```rust
+/// use nanorand::{distributions::StandardNormal, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let value: f64 = rng.sample(StandardNormal);
+/// println!("{}", value);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StandardNormal;
+
+impl Distribution<f64> for StandardNormal {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		ziggurat::ziggurat(
+			rng,
+			true,
+			&ziggurat::NORMAL_X,
+			&ziggurat::NORMAL_F,
+			|x| math::exp(-x * x / 2.0),
+			ziggurat::normal_tail,
+		)
+	}
+}
+
+/// The normal (or Gaussian) distribution, `N(mean, std_dev²)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+	mean: f64,
+	std_dev: f64,
+}
+
+impl Normal {
+	/// Creates a new normal distribution, with the given mean and standard deviation.
+	///
+	/// # Errors
+	/// This function will return an error if either parameter isn't finite,
+	/// or if `std_dev` isn't greater than zero.
+	pub fn new(mean: f64, std_dev: f64) -> Result<Self, DistributionError> {
+		Ok(Self {
+			mean: finite(mean)?,
+			std_dev: positive(std_dev)?,
+		})
+	}
+}
+
+impl Distribution<f64> for Normal {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		self.mean + self.std_dev * StandardNormal.sample(rng)
+	}
+}
+
+/// The log-normal distribution, where `ln(X)` is normally distributed as `N(mu, sigma²)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogNormal {
+	normal: Normal,
+}
+
+impl LogNormal {
+	/// Creates a new log-normal distribution, with the given mean and standard deviation of the underlying normal distribution.
+	///
+	/// # Errors
+	/// This function will return an error if either parameter isn't finite,
+	/// or if `sigma` isn't greater than zero.
+	pub fn new(mu: f64, sigma: f64) -> Result<Self, DistributionError> {
+		Ok(Self {
+			normal: Normal::new(mu, sigma)?,
+		})
+	}
+}
+
+impl Distribution<f64> for LogNormal {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		math::exp(self.normal.sample(rng))
+	}
+}
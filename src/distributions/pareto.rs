@@ -0,0 +1,30 @@
+use super::{positive, Distribution, DistributionError};
+use crate::{gen::OpenClosed01, math, Rng};
+
+/// The Pareto distribution, with the given scale (`x_m`, the minimum value) and shape (`alpha`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pareto {
+	scale: f64,
+	inverse_negative_shape: f64,
+}
+
+impl Pareto {
+	/// Creates a new Pareto distribution, with the given scale and shape.
+	///
+	/// # Errors
+	/// This function will return an error if either parameter isn't finite, or isn't greater than zero.
+	pub fn new(scale: f64, shape: f64) -> Result<Self, DistributionError> {
+		Ok(Self {
+			scale: positive(scale)?,
+			inverse_negative_shape: -1.0 / positive(shape)?,
+		})
+	}
+}
+
+impl Distribution<f64> for Pareto {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		// Inverse transform sampling: F^-1(u) = scale * u^(-1 / shape)
+		let u = rng.generate::<OpenClosed01<f64>>().0;
+		self.scale * math::powf(u, self.inverse_negative_shape)
+	}
+}
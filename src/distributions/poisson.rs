@@ -0,0 +1,108 @@
+use super::{positive, Distribution, DistributionError};
+use crate::{math, Rng};
+
+/// The Poisson distribution, `Poisson(lambda)`, which counts the events in an interval
+/// where they occur independently at an average rate of `lambda`.
+///
+/// Small means are sampled exactly by multiplying uniform numbers together,
+/// and larger ones with Hörmann's PTRS algorithm, from "The Transformed Rejection Method for Generating Poisson Random Variables".
+///
+/// ```rust
+/// use nanorand::{distributions::Poisson, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let requests_this_second = rng.sample(Poisson::new(250.0).unwrap());
+/// println!("{}", requests_this_second);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+	lambda: f64,
+	method: Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Method {
+	Multiplication {
+		/// `e^-lambda`
+		exp_lambda: f64,
+	},
+	Ptrs {
+		a: f64,
+		b: f64,
+		ln_inverse_alpha: f64,
+		v_r: f64,
+	},
+}
+
+impl Poisson {
+	/// Creates a new Poisson distribution, with the given mean.
+	///
+	/// # Errors
+	/// This function will return an error if `lambda` isn't finite, or isn't greater than zero.
+	pub fn new(lambda: f64) -> Result<Self, DistributionError> {
+		let lambda = positive(lambda)?;
+		let method = if lambda < 10.0 {
+			Method::Multiplication {
+				exp_lambda: math::exp(-lambda),
+			}
+		} else {
+			let b = 0.931 + 2.53 * math::sqrt(lambda);
+			Method::Ptrs {
+				a: -0.059 + 0.02483 * b,
+				b,
+				ln_inverse_alpha: math::ln(1.1239 + 1.1328 / (b - 3.4)),
+				v_r: 0.9277 - 3.6224 / (b - 2.0),
+			}
+		};
+		Ok(Self { lambda, method })
+	}
+
+	/// `ln(P(X = k))`
+	fn ln_pmf(&self, k: f64) -> f64 {
+		if k == 0.0 {
+			-self.lambda
+		} else {
+			-math::stirling_error(k)
+				- math::deviance(k, self.lambda)
+				- 0.5 * (math::LN_2PI + math::ln(k))
+		}
+	}
+}
+
+impl Distribution<u64> for Poisson {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> u64 {
+		match self.method {
+			Method::Multiplication { exp_lambda } => {
+				// Count how many uniform numbers we can multiply together before going below e^-lambda.
+				let mut product = rng.generate::<f64>();
+				let mut count = 0;
+				while product > exp_lambda {
+					product *= rng.generate::<f64>();
+					count += 1;
+				}
+				count
+			}
+			Method::Ptrs {
+				a,
+				b,
+				ln_inverse_alpha,
+				v_r,
+			} => loop {
+				let u = rng.generate::<f64>() - 0.5;
+				let v = rng.generate::<f64>();
+				let us = 0.5 - math::abs(u);
+				let k = math::floor((2.0 * a / us + b) * u + self.lambda + 0.43);
+				// The quick acceptance region, which is hit most of the time.
+				if us >= 0.07 && v <= v_r {
+					return k as u64;
+				}
+				if k < 0.0 || (us < 0.013 && v > us) {
+					continue;
+				}
+				if math::ln(v) + ln_inverse_alpha - math::ln(a / (us * us) + b) <= self.ln_pmf(k) {
+					return k as u64;
+				}
+			},
+		}
+	}
+}
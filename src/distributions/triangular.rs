@@ -0,0 +1,46 @@
+use super::{finite, Distribution, DistributionError};
+use crate::{math, Rng};
+
+/// The triangular distribution, over `[min, max]`, with its peak at `mode`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangular {
+	min: f64,
+	max: f64,
+	mode: f64,
+	/// The value of the CDF at `mode`.
+	split: f64,
+}
+
+impl Triangular {
+	/// Creates a new triangular distribution, with the given bounds and mode.
+	///
+	/// # Errors
+	/// This function will return an error if any of the parameters aren't finite,
+	/// if `min` isn't less than `max`, or if `mode` isn't within `[min, max]`.
+	pub fn new(min: f64, max: f64, mode: f64) -> Result<Self, DistributionError> {
+		let (min, max, mode) = (finite(min)?, finite(max)?, finite(mode)?);
+		if min >= max || !(min..=max).contains(&mode) {
+			return Err(DistributionError::OutOfRange);
+		}
+		Ok(Self {
+			min,
+			max,
+			mode,
+			split: (mode - min) / (max - min),
+		})
+	}
+}
+
+impl Distribution<f64> for Triangular {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> f64 {
+		// Inverse transform sampling, with one branch for each side of the peak.
+		let u = rng.generate::<f64>();
+		let value = if u < self.split {
+			self.min + math::sqrt(u * (self.max - self.min) * (self.mode - self.min))
+		} else {
+			self.max - math::sqrt((1.0 - u) * (self.max - self.min) * (self.max - self.mode))
+		};
+		// Rounding can push the result a hair outside of the bounds.
+		value.max(self.min).min(self.max)
+	}
+}
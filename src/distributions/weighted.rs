@@ -0,0 +1,398 @@
+use super::Distribution;
+use crate::Rng;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// A type which can be used as a weight for a [`WeightedIndex`].
+///
+/// This is implemented for all primitive integer and floating point types.
+pub trait Weight: Copy {
+	/// Converts this weight into a [`f64`].
+	fn to_f64(self) -> f64;
+}
+
+macro_rules! weight {
+	($($type:ty),+) => {
+		$(
+			impl Weight for $type {
+				fn to_f64(self) -> f64 {
+					self as f64
+				}
+			}
+		)+
+	};
+}
+
+weight!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// An error returned when constructing or updating a weighted distribution with invalid weights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeightedError {
+	/// There weren't any weights.
+	NoItems,
+	/// One of the weights was negative, NaN, or infinite.
+	InvalidWeight,
+	/// All of the weights were zero, so nothing could ever be picked.
+	AllWeightsZero,
+	/// The index to update was out of bounds.
+	IndexOutOfBounds,
+}
+
+impl Display for WeightedError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::NoItems => write!(f, "no weights were provided"),
+			Self::InvalidWeight => write!(f, "weights must be finite and non-negative"),
+			Self::AllWeightsZero => write!(f, "at least one weight must be greater than zero"),
+			Self::IndexOutOfBounds => write!(f, "index is out of bounds"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeightedError {}
+
+/// A distribution which picks an index into a list of weights, with a probability proportional to its weight.
+///
+/// This uses Vose's alias method, so after the `O(n)` setup, each sample takes constant time:
+/// one [`Rng::generate_range`] call to pick a column of the table, and one coin flip to pick between
+/// the column's own index and its alias.
+///
+/// ```rust
+/// use nanorand::{distributions::WeightedIndex, Rng, WyRand};
+///
+/// let loot = ["common", "rare", "legendary"];
+/// let table = WeightedIndex::new(&[90, 9, 1]).unwrap();
+/// let mut rng = WyRand::new();
+/// println!("You found a {} item!", loot[rng.sample(&table)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedIndex {
+	/// For each column, the chance (out of 2^64) of picking the column's own index, and the alias to pick otherwise.
+	columns: Vec<(u64, usize)>,
+}
+
+impl WeightedIndex {
+	/// Builds the alias table for the given weights.
+	///
+	/// # Errors
+	/// This function will return an error if there are no weights, if any weight is negative or not finite,
+	/// or if all of the weights are zero.
+	pub fn new<W: Weight>(weights: &[W]) -> Result<Self, WeightedError> {
+		if weights.is_empty() {
+			return Err(WeightedError::NoItems);
+		}
+		let mut scaled = Vec::with_capacity(weights.len());
+		let mut total = 0.0;
+		for weight in weights {
+			let weight = weight.to_f64();
+			if !(weight.is_finite() && weight >= 0.0) {
+				return Err(WeightedError::InvalidWeight);
+			}
+			scaled.push(weight);
+			total += weight;
+		}
+		if !total.is_finite() {
+			return Err(WeightedError::InvalidWeight);
+		}
+		if total == 0.0 {
+			return Err(WeightedError::AllWeightsZero);
+		}
+
+		// Scale the weights so that the average is 1, and split them into the columns which are under-full,
+		// and the ones which are over-full.
+		let n = weights.len() as f64;
+		let (mut small, mut large) = (Vec::new(), Vec::new());
+		for (index, weight) in scaled.iter_mut().enumerate() {
+			*weight *= n / total;
+			if *weight < 1.0 {
+				small.push(index);
+			} else {
+				large.push(index);
+			}
+		}
+
+		// Top off each under-full column with part of an over-full one.
+		let mut columns: Vec<(u64, usize)> =
+			(0..weights.len()).map(|index| (u64::MAX, index)).collect();
+		while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+			small.pop();
+			columns[less] = (probability_to_threshold(scaled[less]), more);
+			scaled[more] -= 1.0 - scaled[less];
+			if scaled[more] < 1.0 {
+				large.pop();
+				small.push(more);
+			}
+		}
+		// Anything left over is (within rounding error) exactly full, so it never needs its alias.
+		Ok(Self { columns })
+	}
+
+	/// The number of weights that this distribution picks from.
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> usize {
+		self.columns.len()
+	}
+}
+
+/// Converts a probability in `[0, 1]` into a threshold for a random [`u64`].
+fn probability_to_threshold(probability: f64) -> u64 {
+	// 2^64, as a float. This cast saturates, which handles a probability of exactly 1.
+	(probability * 18_446_744_073_709_551_616.0) as u64
+}
+
+impl Distribution<usize> for WeightedIndex {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> usize {
+		let column = rng.generate_range(0..self.columns.len());
+		let (threshold, alias) = self.columns[column];
+		if rng.generate::<u64>() < threshold {
+			column
+		} else {
+			alias
+		}
+	}
+}
+
+/// A distribution which picks an index into a list of integer weights, with a probability proportional to its weight,
+/// where the weights can be changed after it's been built.
+///
+/// This is backed by a Fenwick tree, so sampling, updating a weight, and adding a new weight all take `O(log n)` time.
+/// As the weights are integers, the probabilities are exact, no matter how many times they're updated.
+///
+/// ```rust
+/// use nanorand::{distributions::DynamicWeightedIndex, Rng, WyRand};
+///
+/// let mut servers = DynamicWeightedIndex::new(&[10, 10, 10]).unwrap();
+/// // The first server is overloaded, so send it less traffic.
+/// servers.set_weight(0, 2).unwrap();
+/// let mut rng = WyRand::new();
+/// println!("Sending the request to server {}", rng.sample(&servers));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicWeightedIndex {
+	weights: Vec<u64>,
+	/// A 1-indexed Fenwick tree, where `tree[i]` is the sum of the weights in `(i - lowest_bit(i), i]`.
+	tree: Vec<u128>,
+	total: u128,
+}
+
+impl DynamicWeightedIndex {
+	/// Builds the tree for the given weights.
+	///
+	/// # Errors
+	/// This function will return an error if there are no weights, or if all of the weights are zero.
+	pub fn new(weights: &[u64]) -> Result<Self, WeightedError> {
+		if weights.is_empty() {
+			return Err(WeightedError::NoItems);
+		}
+		let mut tree = Vec::with_capacity(weights.len() + 1);
+		tree.push(0);
+		tree.extend(weights.iter().map(|&weight| u128::from(weight)));
+		// Build the tree in place, by pushing each node's sum up to its parent.
+		for index in 1..tree.len() {
+			let parent = index + lowest_bit(index);
+			if parent < tree.len() {
+				tree[parent] += tree[index];
+			}
+		}
+		let total = weights.iter().map(|&weight| u128::from(weight)).sum();
+		if total == 0 {
+			return Err(WeightedError::AllWeightsZero);
+		}
+		Ok(Self {
+			weights: weights.to_vec(),
+			tree,
+			total,
+		})
+	}
+
+	/// The number of weights that this distribution picks from.
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> usize {
+		self.weights.len()
+	}
+
+	/// The weight at the given index, if it exists.
+	pub fn weight(&self, index: usize) -> Option<u64> {
+		self.weights.get(index).copied()
+	}
+
+	/// The sum of all of the weights.
+	pub const fn total(&self) -> u128 {
+		self.total
+	}
+
+	/// Changes the weight at the given index.
+	///
+	/// # Errors
+	/// This function will return an error if the index is out of bounds,
+	/// or if this would make all of the weights zero. In both cases, nothing is changed.
+	pub fn set_weight(&mut self, index: usize, weight: u64) -> Result<(), WeightedError> {
+		let old = *self
+			.weights
+			.get(index)
+			.ok_or(WeightedError::IndexOutOfBounds)?;
+		let total = self.total - u128::from(old) + u128::from(weight);
+		if total == 0 {
+			return Err(WeightedError::AllWeightsZero);
+		}
+		self.weights[index] = weight;
+		self.total = total;
+		let mut node = index + 1;
+		while node < self.tree.len() {
+			self.tree[node] = self.tree[node] - u128::from(old) + u128::from(weight);
+			node += lowest_bit(node);
+		}
+		Ok(())
+	}
+
+	/// Adds a new weight to the end of the list.
+	pub fn push(&mut self, weight: u64) {
+		self.weights.push(weight);
+		self.total += u128::from(weight);
+		// The new node covers (node - lowest_bit(node), node], so add up the nodes which cover the rest of that range.
+		let node = self.tree.len();
+		let mut sum = u128::from(weight);
+		let mut child = node - 1;
+		let stop = node - lowest_bit(node);
+		while child > stop {
+			sum += self.tree[child];
+			child -= lowest_bit(child);
+		}
+		self.tree.push(sum);
+	}
+}
+
+/// Isolates the lowest set bit of a number.
+const fn lowest_bit(index: usize) -> usize {
+	index & index.wrapping_neg()
+}
+
+impl Distribution<usize> for DynamicWeightedIndex {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> usize {
+		let mut remaining = rng.generate_range(0..self.total);
+		// Walk down the tree, finding the first index where the running total goes past our random number.
+		let mut position = 0;
+		let mut step = (self.tree.len() - 1).next_power_of_two();
+		while step > 0 {
+			let next = position + step;
+			if next < self.tree.len() && self.tree[next] <= remaining {
+				position = next;
+				remaining -= self.tree[next];
+			}
+			step >>= 1;
+		}
+		position
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::WyRand;
+
+	/// Samples from a distribution, and checks that each index was picked about as often as it should have been.
+	fn check_frequencies<D: Distribution<usize>>(distribution: &D, weights: &[f64]) {
+		const SAMPLES: usize = 200_000;
+		let mut rng = WyRand::new_seed(0x5eed);
+		let mut counts = vec![0_usize; weights.len()];
+		for _ in 0..SAMPLES {
+			counts[rng.sample(distribution)] += 1;
+		}
+		let total: f64 = weights.iter().sum();
+		for (index, (&count, &weight)) in counts.iter().zip(weights).enumerate() {
+			let expected = SAMPLES as f64 * weight / total;
+			// Allow for 5 standard deviations of the binomial distribution.
+			let tolerance = 5.0 * (expected * (1.0 - weight / total)).sqrt();
+			assert!(
+				(count as f64 - expected).abs() <= tolerance,
+				"index {} was picked {} times, expected {}",
+				index,
+				count,
+				expected
+			);
+		}
+	}
+
+	#[test]
+	fn ensure_alias_table_is_accurate() {
+		check_frequencies(
+			&WeightedIndex::new(&[90_u32, 9, 1]).unwrap(),
+			&[90.0, 9.0, 1.0],
+		);
+		check_frequencies(
+			&WeightedIndex::new(&[0.5_f32, 0.0, 2.25, 1.0, 0.0]).unwrap(),
+			&[0.5, 0.0, 2.25, 1.0, 0.0],
+		);
+		let weights: Vec<f64> = (0..100).map(|i| ((i * 37) % 11) as f64).collect();
+		check_frequencies(&WeightedIndex::new(&weights).unwrap(), &weights);
+		check_frequencies(&WeightedIndex::new(&[7_u8]).unwrap(), &[1.0]);
+	}
+
+	#[test]
+	fn ensure_fenwick_tree_is_accurate() {
+		let mut weights: Vec<u64> = (0..37).map(|i| (i * 13) % 7).collect();
+		let mut index = DynamicWeightedIndex::new(&weights).unwrap();
+		let as_floats = |weights: &[u64]| {
+			weights
+				.iter()
+				.map(|&weight| weight as f64)
+				.collect::<Vec<_>>()
+		};
+		check_frequencies(&index, &as_floats(&weights));
+
+		let mut rng = WyRand::new_seed(42);
+		for _ in 0..100 {
+			let position = rng.generate_range(0..weights.len());
+			let weight = rng.generate_range(0_u64..20);
+			weights[position] = weight;
+			index.set_weight(position, weight).unwrap();
+		}
+		for weight in [5, 0, 100, 3] {
+			weights.push(weight);
+			index.push(weight);
+		}
+		assert_eq!(
+			index.total(),
+			weights.iter().map(|&w| u128::from(w)).sum::<u128>()
+		);
+		// Pushing and updating should leave us with the same tree as building it from scratch.
+		assert_eq!(index, DynamicWeightedIndex::new(&weights).unwrap());
+		check_frequencies(&index, &as_floats(&weights));
+	}
+
+	#[test]
+	fn ensure_invalid_weights_are_rejected() {
+		assert_eq!(
+			WeightedIndex::new::<u32>(&[]).unwrap_err(),
+			WeightedError::NoItems
+		);
+		assert_eq!(
+			WeightedIndex::new(&[1, -1]).unwrap_err(),
+			WeightedError::InvalidWeight
+		);
+		assert_eq!(
+			WeightedIndex::new(&[1.0, f64::NAN]).unwrap_err(),
+			WeightedError::InvalidWeight
+		);
+		assert_eq!(
+			WeightedIndex::new(&[0, 0]).unwrap_err(),
+			WeightedError::AllWeightsZero
+		);
+
+		let mut index = DynamicWeightedIndex::new(&[0, 3]).unwrap();
+		assert_eq!(
+			index.set_weight(1, 0).unwrap_err(),
+			WeightedError::AllWeightsZero
+		);
+		assert_eq!(
+			index.set_weight(2, 1).unwrap_err(),
+			WeightedError::IndexOutOfBounds
+		);
+		assert_eq!(index.weight(1), Some(3));
+		assert_eq!(
+			DynamicWeightedIndex::new(&[0, 0]).unwrap_err(),
+			WeightedError::AllWeightsZero
+		);
+	}
+}
@@ -0,0 +1,150 @@
+use super::{finite, Distribution, DistributionError};
+use crate::{gen::OpenClosed01, math, Rng};
+
+/// The Zipf distribution, over `1..=n`, where the probability of `k` is proportional to `k^-s`.
+///
+/// This is sampled with the rejection-inversion method from Hörmann & Derflinger's
+/// "Rejection-inversion to generate variates from monotone discrete distributions",
+/// which takes constant time, no matter how large `n` is.
+///
+/// ```rust
+/// use nanorand::{distributions::Zipf, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// // Pick which of 10,000 pages gets the next hit, where the most popular pages get the most traffic.
+/// let page = rng.sample(Zipf::new(10_000, 1.1).unwrap());
+/// assert!((1..=10_000).contains(&page));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Zipf {
+	n: u64,
+	s: f64,
+	h_integral_x1: f64,
+	h_integral_n: f64,
+	threshold: f64,
+}
+
+impl Zipf {
+	/// Creates a new Zipf distribution, over `1..=n`, with the exponent `s`.
+	///
+	/// # Errors
+	/// This function will return an error if `n` is zero, if `s` isn't finite, or if `s` is negative.
+	pub fn new(n: u64, s: f64) -> Result<Self, DistributionError> {
+		if n == 0 {
+			return Err(DistributionError::NonPositive);
+		}
+		if finite(s)? < 0.0 {
+			return Err(DistributionError::OutOfRange);
+		}
+		let mut this = Self {
+			n,
+			s,
+			h_integral_x1: 0.0,
+			h_integral_n: 0.0,
+			threshold: 0.0,
+		};
+		this.h_integral_x1 = this.h_integral(1.5) - 1.0;
+		this.h_integral_n = this.h_integral(n as f64 + 0.5);
+		this.threshold = 2.0 - this.h_integral_inverse(this.h_integral(2.5) - this.h(2.0));
+		Ok(this)
+	}
+
+	/// `h(x) = x^-s`
+	fn h(&self, x: f64) -> f64 {
+		math::exp(-self.s * math::ln(x))
+	}
+
+	/// `H(x)`, the integral of `h` from 1 to `x`, which is `(x^(1 - s) - 1) / (1 - s)`, or `ln(x)` when `s` is 1.
+	fn h_integral(&self, x: f64) -> f64 {
+		let ln_x = math::ln(x);
+		let t = (1.0 - self.s) * ln_x;
+		// expm1(t) / t, which tends to 1 as t tends to 0.
+		let helper = if math::abs(t) > 1e-8 {
+			math::exp_m1(t) / t
+		} else {
+			1.0 + t * 0.5 * (1.0 + t / 3.0 * (1.0 + t * 0.25))
+		};
+		helper * ln_x
+	}
+
+	/// The inverse of [`Zipf::h_integral`].
+	fn h_integral_inverse(&self, x: f64) -> f64 {
+		let mut t = x * (1.0 - self.s);
+		if t < -1.0 {
+			// Rounding error can push this a little too far.
+			t = -1.0;
+		}
+		// ln1p(t) / t, which tends to 1 as t tends to 0.
+		let helper = if math::abs(t) > 1e-8 {
+			math::ln_1p(t) / t
+		} else {
+			1.0 - t * (0.5 - t * (1.0 / 3.0 - t * 0.25))
+		};
+		math::exp(helper * x)
+	}
+}
+
+impl Distribution<u64> for Zipf {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> u64 {
+		loop {
+			let u = self.h_integral_n
+				+ rng.generate::<f64>() * (self.h_integral_x1 - self.h_integral_n);
+			let x = self.h_integral_inverse(u);
+			let k = math::floor(x + 0.5);
+			let k = if k < 1.0 {
+				1.0
+			} else if k > self.n as f64 {
+				self.n as f64
+			} else {
+				k
+			};
+			if k - x <= self.threshold || u >= self.h_integral(k + 0.5) - self.h(k) {
+				return k as u64;
+			}
+		}
+	}
+}
+
+/// The Zeta distribution, over all positive integers, where the probability of `k` is proportional to `k^-s`.
+///
+/// This is sampled with the rejection method from Devroye's "Non-Uniform Random Variate Generation".
+/// Results that don't fit in a [`u64`] saturate to [`u64::MAX`], which only matters when `s` is very close to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Zeta {
+	s_minus_1: f64,
+	/// `2^(s - 1)`
+	b: f64,
+}
+
+impl Zeta {
+	/// Creates a new Zeta distribution, with the exponent `s`.
+	///
+	/// # Errors
+	/// This function will return an error if `s` isn't finite, or isn't greater than 1.
+	pub fn new(s: f64) -> Result<Self, DistributionError> {
+		if finite(s)? <= 1.0 {
+			return Err(DistributionError::OutOfRange);
+		}
+		Ok(Self {
+			s_minus_1: s - 1.0,
+			b: math::powf(2.0, s - 1.0),
+		})
+	}
+}
+
+impl Distribution<u64> for Zeta {
+	fn sample<Generator: Rng<OUTPUT>, const OUTPUT: usize>(&self, rng: &mut Generator) -> u64 {
+		loop {
+			let u = rng.generate::<OpenClosed01<f64>>().0;
+			let x = math::floor(math::powf(u, -1.0 / self.s_minus_1));
+			if x >= u64::MAX as f64 {
+				return u64::MAX;
+			}
+			let t = math::powf(1.0 + 1.0 / x, self.s_minus_1);
+			let v = rng.generate::<f64>();
+			if v * x * (t - 1.0) * self.b <= t * (self.b - 1.0) {
+				return x as u64;
+			}
+		}
+	}
+}
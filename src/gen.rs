@@ -1,5 +1,13 @@
 use crate::Rng;
-use core::ops::{Bound, RangeBounds};
+use core::{
+	fmt::{self, Display, Formatter},
+	num::{
+		NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+		NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+	},
+	ops::{Bound, RangeBounds},
+	time::Duration,
+};
 
 macro_rules! gen {
 	($($type:ty),+) => {
@@ -8,81 +16,327 @@ macro_rules! gen {
 				fn random(rng: &mut Generator) -> Self {
 					let mut bytes = [0u8; core::mem::size_of::<$type>()];
 					rng.fill_bytes(&mut bytes);
-					Self::from_ne_bytes(bytes)
+					Self::from_le_bytes(bytes)
 				}
 			}
 		)+
 	};
 }
 
+/// Turns the bounds of an integer range into inclusive lower and upper bounds,
+/// returning early with a [`RangeError`] if the range doesn't contain anything.
+macro_rules! inclusive_bounds {
+	($type:ty, $bounds:expr) => {{
+		let bounds = $bounds;
+		if let (
+			Bound::Included(lower) | Bound::Excluded(lower),
+			Bound::Included(upper) | Bound::Excluded(upper),
+		) = (bounds.start_bound(), bounds.end_bound())
+		{
+			if lower > upper {
+				return Err(RangeError::Inverted);
+			}
+		}
+		let lower = match bounds.start_bound() {
+			Bound::Included(lower) => Some(*lower),
+			Bound::Excluded(lower) => lower.checked_add(1),
+			Bound::Unbounded => Some(<$type>::MIN),
+		};
+		let upper = match bounds.end_bound() {
+			Bound::Included(upper) => Some(*upper),
+			Bound::Excluded(upper) => upper.checked_sub(1),
+			Bound::Unbounded => Some(<$type>::MAX),
+		};
+		match (lower, upper) {
+			(Some(lower), Some(upper)) if lower <= upper => (lower, upper),
+			_ => return Err(RangeError::Empty),
+		}
+	}};
+}
+
 macro_rules! range {
-	($(($type:ty, $bigger:ty, $signed:ty)),+) => {
+	($(($type:ty, $bigger:ty)),+) => {
 		$(
 			impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomRange<Generator, OUTPUT> for $type {
-				fn random_range<Bounds: RangeBounds<Self>>(rng: &mut Generator, bounds: Bounds) -> Self {
+				fn try_random_range<Bounds: RangeBounds<Self>>(rng: &mut Generator, bounds: Bounds) -> Result<Self, RangeError> {
 					const BITS: $bigger = core::mem::size_of::<$type>() as $bigger * 8;
-					let lower = match bounds.start_bound() {
-						Bound::Included(lower) => *lower,
-						Bound::Excluded(lower) => lower.saturating_add(1),
-						Bound::Unbounded => <$type>::MIN,
-					};
-					let upper = match bounds.end_bound() {
-						Bound::Included(upper) => upper.saturating_add(1),
-						Bound::Excluded(upper) => *upper,
-						Bound::Unbounded => <$type>::MAX,
-					};
-					assert!(upper >= lower, "{} >= {} (lower bound was bigger than upper bound)", upper, lower);
-					let upper = upper.saturating_sub(lower);
+					let (lower, upper) = inclusive_bounds!($type, bounds);
+					// This wraps around to zero if the range covers every possible value.
+					let range = upper.wrapping_sub(lower).wrapping_add(1);
+					if range == 0 {
+						return Ok(Self::random(rng));
+					}
 					let mut value = Self::random(rng);
-					let mut m = (upper as $bigger).wrapping_mul(value as $bigger);
-					if (m as $type) < upper {
-						let t = (!upper + 1) % upper;
+					let mut m = (range as $bigger).wrapping_mul(value as $bigger);
+					if (m as $type) < range {
+						let t = (!range + 1) % range;
 						while (m as $type) < t {
 							value = Self::random(rng);
-							m = (upper as $bigger).wrapping_mul(value as $bigger);
+							m = (range as $bigger).wrapping_mul(value as $bigger);
 						}
 					}
-					(m >> BITS) as $type + lower
+					Ok((m >> BITS) as $type + lower)
 				}
 			}
+		)+
+	}
+}
 
+macro_rules! signed_range {
+	($(($type:ty, $signed:ty)),+) => {
+		$(
 			impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomRange<Generator, OUTPUT> for $signed {
-				fn random_range<Bounds: RangeBounds<Self>>(r: &mut Generator, bounds: Bounds) -> Self {
-					let lower = match bounds.start_bound() {
-						Bound::Included(lower) => *lower,
-						Bound::Excluded(lower) => lower.saturating_add(1),
-						Bound::Unbounded => <$signed>::MIN
-					};
-					let upper = match bounds.end_bound() {
-						Bound::Included(upper) => *upper,
-						Bound::Excluded(upper) => upper.saturating_sub(1),
-						Bound::Unbounded => <$signed>::MAX,
-					};
-					assert!(upper >= lower, "{} >= {} (lower bound was bigger than upper bound)", upper, lower);
+				fn try_random_range<Bounds: RangeBounds<Self>>(r: &mut Generator, bounds: Bounds) -> Result<Self, RangeError> {
+					let (lower, upper) = inclusive_bounds!($signed, bounds);
 					let lower = lower.wrapping_sub(<$signed>::MIN) as $type;
 					let upper = upper.wrapping_sub(<$signed>::MIN) as $type;
-					<$type>::random_range(r, lower..=upper).wrapping_add(<$signed>::MIN as $type) as $signed
+					<$type>::try_random_range(r, lower..=upper)
+						.map(|number| number.wrapping_add(<$signed>::MIN as $type) as $signed)
+				}
+			}
+		)+
+	}
+}
+
+macro_rules! float_range {
+	($($type:ty),+) => {
+		$(
+			impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomRange<Generator, OUTPUT> for $type {
+				fn try_random_range<Bounds: RangeBounds<Self>>(rng: &mut Generator, bounds: Bounds) -> Result<Self, RangeError> {
+					let (lower, lower_inclusive) = match bounds.start_bound() {
+						Bound::Included(lower) => (*lower, true),
+						Bound::Excluded(lower) => (*lower, false),
+						Bound::Unbounded => return Err(RangeError::NonFinite),
+					};
+					let (upper, upper_inclusive) = match bounds.end_bound() {
+						Bound::Included(upper) => (*upper, true),
+						Bound::Excluded(upper) => (*upper, false),
+						Bound::Unbounded => return Err(RangeError::NonFinite),
+					};
+					if !lower.is_finite() || !upper.is_finite() {
+						return Err(RangeError::NonFinite);
+					}
+					if lower > upper {
+						return Err(RangeError::Inverted);
+					}
+					if lower == upper && !(lower_inclusive && upper_inclusive) {
+						return Err(RangeError::Empty);
+					}
+					if !lower_inclusive && !upper_inclusive {
+						// Make sure there's at least one float strictly between the two bounds.
+						let next = if lower == 0.0 {
+							<$type>::from_bits(1)
+						} else if lower > 0.0 {
+							<$type>::from_bits(lower.to_bits() + 1)
+						} else {
+							<$type>::from_bits(lower.to_bits() - 1)
+						};
+						if upper <= next {
+							return Err(RangeError::Empty);
+						}
+					}
+					let scale = upper - lower;
+					loop {
+						let value = if upper_inclusive {
+							Closed01::<Self>::random(rng).0
+						} else {
+							Self::random(rng)
+						};
+						// If the range is wider than the largest float, interpolate between
+						// the bounds instead, so the intermediate value can't overflow.
+						let scaled = if scale.is_finite() {
+							lower + scale * value
+						} else {
+							lower * (1.0 - value) + upper * value
+						};
+						// Rounding can push the result onto (or past) either bound,
+						// so reject anything that isn't actually inside the range.
+						let above_lower = scaled > lower || (lower_inclusive && scaled == lower);
+						let below_upper = scaled < upper || (upper_inclusive && scaled == upper);
+						if above_lower && below_upper {
+							return Ok(scaled);
+						}
+					}
 				}
 			}
 		)+
+	};
+}
+
+/// Multiplies two [`u128`]s, returning the high and low halves of the 256-bit product.
+const fn wide_mul_u128(a: u128, b: u128) -> (u128, u128) {
+	const MASK: u128 = u64::MAX as u128;
+	let (a_high, a_low) = (a >> 64, a & MASK);
+	let (b_high, b_low) = (b >> 64, b & MASK);
+	let low_low = a_low * b_low;
+	let high_low = a_high * b_low;
+	let low_high = a_low * b_high;
+	let high_high = a_high * b_high;
+	// This can't overflow: it's at most (2^64 - 1) * 2 + (2^64 - 1)^2, which is 2^128 - 1.
+	let cross = (low_low >> 64) + (high_low & MASK) + low_high;
+	let high = high_high + (high_low >> 64) + (cross >> 64);
+	let low = (cross << 64) | (low_low & MASK);
+	(high, low)
+}
+
+/// As there's no bigger integer type to widen [`u128`] into, this does the same thing as the `range!` macro,
+/// but with a software 128x128->256 bit multiply.
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomRange<Generator, OUTPUT> for u128 {
+	fn try_random_range<Bounds: RangeBounds<Self>>(
+		rng: &mut Generator,
+		bounds: Bounds,
+	) -> Result<Self, RangeError> {
+		let (lower, upper) = inclusive_bounds!(u128, bounds);
+		let range = upper.wrapping_sub(lower).wrapping_add(1);
+		if range == 0 {
+			return Ok(Self::random(rng));
+		}
+		let (mut high, mut low) = wide_mul_u128(range, Self::random(rng));
+		if low < range {
+			let t = (!range + 1) % range;
+			while low < t {
+				let (new_high, new_low) = wide_mul_u128(range, Self::random(rng));
+				high = new_high;
+				low = new_low;
+			}
+		}
+		Ok(high + lower)
+	}
+}
+
+/// The first of the UTF-16 surrogate code points, which aren't valid [`char`]s.
+const SURROGATE_START: u32 = 0xD800;
+/// How many surrogate code points there are.
+const SURROGATE_COUNT: u32 = 0x800;
+
+/// Maps a [`char`] to its position among all valid [`char`]s, skipping over the surrogates.
+const fn char_to_index(c: char) -> u32 {
+	let c = c as u32;
+	if c >= SURROGATE_START {
+		c - SURROGATE_COUNT
+	} else {
+		c
+	}
+}
+
+/// The inverse of [`char_to_index`].
+fn index_to_char(index: u32) -> char {
+	let c = if index >= SURROGATE_START {
+		index + SURROGATE_COUNT
+	} else {
+		index
+	};
+	char::from_u32(c).expect("index was outside of the range of valid chars")
+}
+
+/// Generates a [`char`] uniformly from all Unicode scalar values, excluding the surrogates.
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for char {
+	fn random(rng: &mut Generator) -> Self {
+		index_to_char(u32::random_range(rng, ..=char_to_index(char::MAX)))
 	}
 }
 
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomRange<Generator, OUTPUT> for char {
+	fn try_random_range<Bounds: RangeBounds<Self>>(
+		rng: &mut Generator,
+		bounds: Bounds,
+	) -> Result<Self, RangeError> {
+		let lower = match bounds.start_bound() {
+			Bound::Included(lower) => Bound::Included(char_to_index(*lower)),
+			Bound::Excluded(lower) => Bound::Excluded(char_to_index(*lower)),
+			Bound::Unbounded => Bound::Included(char_to_index('\0')),
+		};
+		let upper = match bounds.end_bound() {
+			Bound::Included(upper) => Bound::Included(char_to_index(*upper)),
+			Bound::Excluded(upper) => Bound::Excluded(char_to_index(*upper)),
+			Bound::Unbounded => Bound::Included(char_to_index(char::MAX)),
+		};
+		u32::try_random_range(rng, (lower, upper)).map(index_to_char)
+	}
+}
+
+macro_rules! non_zero {
+	($(($non_zero:ty, $type:ty)),+) => {
+		$(
+			/// Generates a non-zero integer, by generating integers until one of them isn't zero.
+			impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for $non_zero {
+				fn random(rng: &mut Generator) -> Self {
+					loop {
+						if let Some(number) = <$non_zero>::new(<$type>::random(rng)) {
+							return number;
+						}
+					}
+				}
+			}
+		)+
+	};
+}
+
+macro_rules! tuple {
+	($($name:ident)+) => {
+		impl<Generator: Rng<OUTPUT>, const OUTPUT: usize, $($name: RandomGen<Generator, OUTPUT>),+> RandomGen<Generator, OUTPUT> for ($($name,)+) {
+			fn random(rng: &mut Generator) -> Self {
+				($($name::random(rng),)+)
+			}
+		}
+	};
+}
+
 /// A trait used for generating a random object with an RNG,
 pub trait RandomGen<Generator: Rng<OUTPUT>, const OUTPUT: usize> {
 	/// Return a random instance of the implementing type, from the specified RNG instance.
 	fn random(rng: &mut Generator) -> Self;
 }
 
+/// An error returned when a random number can't be generated within a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeError {
+	/// The range doesn't contain any values, such as `5..5`.
+	Empty,
+	/// The lower bound of the range is greater than the upper bound, such as `10..=5`.
+	Inverted,
+	/// A bound of a floating point range is NaN or infinite, or is missing entirely.
+	NonFinite,
+}
+
+impl Display for RangeError {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Empty => write!(f, "range was empty"),
+			Self::Inverted => write!(f, "lower bound was bigger than upper bound"),
+			Self::NonFinite => write!(f, "range bounds must be finite"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
 /// A trait used for generating a random number within a range, with an RNG,
 pub trait RandomRange<Generator: Rng<OUTPUT>, const OUTPUT: usize>:
-	RandomGen<Generator, OUTPUT>
+	RandomGen<Generator, OUTPUT> + Sized
 {
+	/// Return a ranged number of the implementing type, from the specified RNG instance.
+	///
+	/// # Errors
+	/// This function will return a [`RangeError`] if the range doesn't contain any values,
+	/// or if it's a floating point range with bounds that aren't finite.
+	fn try_random_range<Bounds: RangeBounds<Self>>(
+		rng: &mut Generator,
+		range: Bounds,
+	) -> Result<Self, RangeError>;
+
 	/// Return a ranged number of the implementing type, from the specified RNG instance.
 	///
 	/// # Panics
-	/// This function will panic if the lower bound of the range is greater than the upper bound.
-	fn random_range<Bounds: RangeBounds<Self>>(nng: &mut Generator, range: Bounds) -> Self;
+	/// This function will panic if the range doesn't contain any values,
+	/// or if it's a floating point range with bounds that aren't finite.
+	fn random_range<Bounds: RangeBounds<Self>>(rng: &mut Generator, range: Bounds) -> Self {
+		match Self::try_random_range(rng, range) {
+			Ok(number) => number,
+			Err(error) => panic!("invalid range: {}", error),
+		}
+	}
 }
 
 impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for bool {
@@ -91,35 +345,236 @@ impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> f
 	}
 }
 
-impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for f32 {
+/// A float in the half-open interval `(0, 1]`.
+///
+/// This is useful when the value is going to be passed to something like `ln`, which doesn't like zero.
+///
+/// ```rust
+/// use nanorand::{OpenClosed01, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let OpenClosed01(number) = rng.generate::<OpenClosed01<f64>>();
+/// assert!(number > 0.0 && number <= 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct OpenClosed01<T>(pub T);
+
+/// A float in the closed interval `[0, 1]`.
+///
+/// ```rust
+/// use nanorand::{Closed01, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let Closed01(number) = rng.generate::<Closed01<f64>>();
+/// assert!((0.0..=1.0).contains(&number));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Closed01<T>(pub T);
+
+/// A float in the half-open interval `[0, 1)`, which can be any representable float in that range.
+///
+/// Plain float generation only produces multiples of `2^-24` (for [`f32`]) or `2^-53` (for [`f64`]).
+/// This picks the exponent with the appropriate (geometric) probability first, and then fills the entire mantissa,
+/// so values closer to zero get the extra precision that the float format has available for them.
+///
+/// ```rust
+/// use nanorand::{Dense01, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let Dense01(number) = rng.generate::<Dense01<f64>>();
+/// assert!((0.0..1.0).contains(&number));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Dense01<T>(pub T);
+
+macro_rules! float {
+	($(($type:ty, $bits:ty, $precision:expr)),+) => {
+		$(
+			/// Generates a float in the half-open interval `[0, 1)`, using the top bits of a random integer
+			/// as the mantissa, so every possible output is equally likely.
+			impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for $type {
+				fn random(rng: &mut Generator) -> Self {
+					const SHIFT: u32 = <$bits>::BITS - $precision;
+					const SCALE: $type = 1.0 / (1 as $bits << $precision) as $type;
+					(<$bits>::random(rng) >> SHIFT) as $type * SCALE
+				}
+			}
+
+			impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for OpenClosed01<$type> {
+				fn random(rng: &mut Generator) -> Self {
+					const SHIFT: u32 = <$bits>::BITS - $precision;
+					const SCALE: $type = 1.0 / (1 as $bits << $precision) as $type;
+					Self(((<$bits>::random(rng) >> SHIFT) + 1) as $type * SCALE)
+				}
+			}
+
+			impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for Closed01<$type> {
+				fn random(rng: &mut Generator) -> Self {
+					const MAX: $bits = 1 << $precision;
+					const SCALE: $type = 1.0 / MAX as $type;
+					Self(<$bits>::random_range(rng, 0..=MAX) as $type * SCALE)
+				}
+			}
+
+			impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for Dense01<$type> {
+				fn random(rng: &mut Generator) -> Self {
+					const MANTISSA_BITS: u32 = $precision - 1;
+					// The biased exponent of floats in [0.5, 1).
+					const HALF_EXPONENT: $bits = (<$type>::MAX_EXP - 2) as $bits;
+					// Every leading zero bit halves the interval we land in.
+					let mut exponent = HALF_EXPONENT;
+					loop {
+						let bits = <$bits>::random(rng);
+						let zeros = bits.leading_zeros() as $bits;
+						if zeros >= exponent {
+							// We've fallen into the subnormals, which are all evenly spaced.
+							exponent = 0;
+							break;
+						}
+						exponent -= zeros;
+						if bits != 0 {
+							break;
+						}
+					}
+					let mantissa = <$bits>::random(rng) >> (<$bits>::BITS - MANTISSA_BITS);
+					Self(<$type>::from_bits((exponent << MANTISSA_BITS) | mantissa))
+				}
+			}
+		)+
+	};
+}
+
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for () {
+	fn random(_rng: &mut Generator) -> Self {}
+}
+
+impl<
+		Generator: Rng<OUTPUT>,
+		const OUTPUT: usize,
+		Contents: RandomGen<Generator, OUTPUT>,
+		const LENGTH: usize,
+	> RandomGen<Generator, OUTPUT> for [Contents; LENGTH]
+{
+	fn random(rng: &mut Generator) -> Self {
+		[(); LENGTH].map(|_| Contents::random(rng))
+	}
+}
+
+/// Generates [`None`] half of the time, and [`Some`] with a random value the other half.
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize, Contents: RandomGen<Generator, OUTPUT>>
+	RandomGen<Generator, OUTPUT> for Option<Contents>
+{
+	fn random(rng: &mut Generator) -> Self {
+		if bool::random(rng) {
+			Some(Contents::random(rng))
+		} else {
+			None
+		}
+	}
+}
+
+/// Generates [`Ok`] half of the time, and [`Err`] the other half, each with a random value.
+impl<
+		Generator: Rng<OUTPUT>,
+		const OUTPUT: usize,
+		Value: RandomGen<Generator, OUTPUT>,
+		Error: RandomGen<Generator, OUTPUT>,
+	> RandomGen<Generator, OUTPUT> for Result<Value, Error>
+{
 	fn random(rng: &mut Generator) -> Self {
-		(u32::random(rng) as f32) / (u32::MAX as f32)
+		if bool::random(rng) {
+			Ok(Value::random(rng))
+		} else {
+			Err(Error::random(rng))
+		}
 	}
 }
 
-impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for f64 {
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize, Contents: RandomGen<Generator, OUTPUT>>
+	RandomGen<Generator, OUTPUT> for Wrapping<Contents>
+{
 	fn random(rng: &mut Generator) -> Self {
-		(u64::random(rng) as f64) / (u64::MAX as f64)
+		Wrapping(Contents::random(rng))
+	}
+}
+
+/// Generates any possible [`Duration`], with a uniformly random number of seconds and nanoseconds.
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> RandomGen<Generator, OUTPUT> for Duration {
+	fn random(rng: &mut Generator) -> Self {
+		let seconds = u64::random(rng);
+		let nanoseconds = u32::random_range(rng, 0..1_000_000_000);
+		Duration::new(seconds, nanoseconds)
 	}
 }
 
 gen!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
-range!(
-	(u8, u16, i8),
-	(u16, u32, i16),
-	(u32, u64, i32),
-	(u64, u128, i64)
+non_zero!(
+	(NonZeroI8, i8),
+	(NonZeroU8, u8),
+	(NonZeroI16, i16),
+	(NonZeroU16, u16),
+	(NonZeroI32, i32),
+	(NonZeroU32, u32),
+	(NonZeroI64, i64),
+	(NonZeroU64, u64),
+	(NonZeroI128, i128),
+	(NonZeroU128, u128),
+	(NonZeroIsize, isize),
+	(NonZeroUsize, usize)
 );
+tuple!(A);
+tuple!(A B);
+tuple!(A B C);
+tuple!(A B C D);
+tuple!(A B C D E);
+tuple!(A B C D E F);
+tuple!(A B C D E F G);
+tuple!(A B C D E F G H);
+tuple!(A B C D E F G H I);
+tuple!(A B C D E F G H I J);
+tuple!(A B C D E F G H I J K);
+tuple!(A B C D E F G H I J K L);
+range!((u8, u16), (u16, u32), (u32, u64), (u64, u128));
+float!((f32, u32, 24), (f64, u64, 53));
+float_range!(f32, f64);
 #[cfg(target_pointer_width = "16")]
-range!((usize, u32, isize));
+range!((usize, u32));
 #[cfg(target_pointer_width = "32")]
-range!((usize, u64, isize));
+range!((usize, u64));
 #[cfg(target_pointer_width = "64")]
-range!((usize, u128, isize));
+range!((usize, u128));
+signed_range!(
+	(u8, i8),
+	(u16, i16),
+	(u32, i32),
+	(u64, i64),
+	(u128, i128),
+	(usize, isize)
+);
 
 #[cfg(test)]
 mod tests {
+	use super::{Closed01, Dense01, OpenClosed01, RangeError};
 	use crate::{Rng, WyRand};
+	use core::{
+		num::{NonZeroI128, NonZeroU8, Wrapping},
+		ops::Bound,
+		time::Duration,
+	};
+
+	#[test]
+	fn ensure_generation_is_portable() {
+		// WyRand's first two outputs for this seed are 0xae4a7cbfdda9b434 and 0xe9cc09d33d38d9d2,
+		// and these have to come out the same on big-endian targets too.
+		assert_eq!(WyRand::new_seed(42).generate::<u32>(), 0xdda9b434);
+		assert_eq!(WyRand::new_seed(42).generate::<u16>(), 0xb434);
+		assert_eq!(
+			WyRand::new_seed(42).generate::<u128>(),
+			0xe9cc09d33d38d9d2ae4a7cbfdda9b434
+		);
+		assert_eq!(WyRand::new_seed(42).generate::<f64>(), 0.6808240860388313);
+	}
+
 	#[test]
 	fn ensure_unsigned_in_range() {
 		let mut rng = WyRand::new();
@@ -156,6 +611,65 @@ mod tests {
 			);
 		}
 	}
+	#[test]
+	fn ensure_128_bit_in_range() {
+		let mut rng = WyRand::new();
+		for _ in 0..1000 {
+			let number = rng.generate_range(10_u128..=20);
+			assert!(
+				(10..=20).contains(&number),
+				"{} was outside of 10..=20",
+				number
+			);
+
+			let lower = 1_u128 << 100;
+			let upper = (1_u128 << 100) + (1 << 70);
+			let number = rng.generate_range(lower..upper);
+			assert!(
+				(lower..upper).contains(&number),
+				"{} was outside of {}..{}",
+				number,
+				lower,
+				upper
+			);
+
+			let number = rng.generate_range((u128::MAX - 3)..);
+			assert!(
+				((u128::MAX - 3)..).contains(&number),
+				"{} was outside of {}..",
+				number,
+				u128::MAX - 3
+			);
+
+			let number = rng.generate_range(i128::MIN..=(i128::MIN + 1));
+			assert!(
+				(i128::MIN..=(i128::MIN + 1)).contains(&number),
+				"{} was outside of {}..={}",
+				number,
+				i128::MIN,
+				i128::MIN + 1
+			);
+
+			let number = rng.generate_range(-(1_i128 << 90)..(1 << 90));
+			assert!(
+				(-(1_i128 << 90)..(1 << 90)).contains(&number),
+				"{} was outside of -2^90..2^90",
+				number
+			);
+		}
+	}
+
+	#[test]
+	fn ensure_wide_multiply_is_correct() {
+		assert_eq!(super::wide_mul_u128(0, u128::MAX), (0, 0));
+		assert_eq!(super::wide_mul_u128(1 << 64, 1 << 64), (1, 0));
+		assert_eq!(super::wide_mul_u128(u128::MAX, 2), (1, u128::MAX - 1));
+		assert_eq!(
+			super::wide_mul_u128(u128::MAX, u128::MAX),
+			(u128::MAX - 1, 1)
+		);
+	}
+
 	#[test]
 	fn ensure_signed_in_range() {
 		let mut rng = WyRand::new();
@@ -192,15 +706,225 @@ mod tests {
 		let mut rng = WyRand::new();
 		for _ in 0..1000 {
 			let number = rng.generate::<f32>();
-			assert!(1.0 >= number, "{} was bigger than 1.0", number);
+			assert!(1.0 > number, "{} was not smaller than 1.0", number);
 			assert!(number >= 0.0, "0 was bigger than {}", number);
 
 			let number = rng.generate::<f64>();
+			assert!(1.0 > number, "{} was not smaller than 1.0", number);
+			assert!(number >= 0.0, "0 was bigger than {}", number);
+
+			let OpenClosed01(number) = rng.generate::<OpenClosed01<f64>>();
 			assert!(1.0 >= number, "{} was bigger than 1.0", number);
+			assert!(number > 0.0, "{} was not bigger than 0", number);
+
+			let Closed01(number) = rng.generate::<Closed01<f32>>();
+			assert!(1.0 >= number, "{} was bigger than 1.0", number);
+			assert!(number >= 0.0, "0 was bigger than {}", number);
+
+			let Dense01(number) = rng.generate::<Dense01<f64>>();
+			assert!(1.0 > number, "{} was not smaller than 1.0", number);
 			assert!(number >= 0.0, "0 was bigger than {}", number);
 		}
 	}
 
+	#[derive(Clone)]
+	struct ConstantRng(u8);
+
+	impl Rng<8> for ConstantRng {
+		fn rand(&mut self) -> [u8; 8] {
+			[self.0; 8]
+		}
+	}
+
+	#[test]
+	fn ensure_float_edges_are_exact() {
+		let mut ones = ConstantRng(0xFF);
+		assert_eq!(ones.generate::<f64>(), 1.0 - f64::EPSILON / 2.0);
+		assert_eq!(ones.generate::<f32>(), 1.0 - f32::EPSILON / 2.0);
+		assert_eq!(ones.generate::<OpenClosed01<f64>>().0, 1.0);
+		assert_eq!(ones.generate::<Dense01<f64>>().0, 1.0 - f64::EPSILON / 2.0);
+
+		let mut zeros = ConstantRng(0);
+		assert_eq!(zeros.generate::<f64>(), 0.0);
+		assert_eq!(zeros.generate::<OpenClosed01<f64>>().0, f64::EPSILON / 2.0);
+		assert_eq!(zeros.generate::<OpenClosed01<f32>>().0, f32::EPSILON / 2.0);
+		assert_eq!(zeros.generate::<Dense01<f64>>().0, 0.0);
+		assert_eq!(zeros.generate::<Dense01<f32>>().0, 0.0);
+	}
+
+	#[test]
+	fn ensure_float_ranges_generate_properly() {
+		let mut rng = WyRand::new();
+		for _ in 0..1000 {
+			let number = rng.generate_range(0.5_f64..2.0);
+			assert!(
+				(0.5..2.0).contains(&number),
+				"{} was outside of 0.5..2.0",
+				number
+			);
+
+			let number = rng.generate_range(-3.0_f32..=-1.5);
+			assert!(
+				(-3.0..=-1.5).contains(&number),
+				"{} was outside of -3.0..=-1.5",
+				number
+			);
+
+			let number = rng.generate_range(f64::MIN..f64::MAX);
+			assert!(number.is_finite(), "{} was not finite", number);
+
+			let number = rng.generate_range(1.0_f64..=1.0);
+			assert_eq!(number, 1.0, "{} was outside of 1.0..=1.0", number);
+
+			// There's only one f32 between these bounds, so rounding will usually land on a bound.
+			let lower = 1.0_f32;
+			let upper = f32::from_bits(lower.to_bits() + 2);
+			let number = rng.generate_range((Bound::Excluded(lower), Bound::Excluded(upper)));
+			assert_eq!(
+				number,
+				f32::from_bits(lower.to_bits() + 1),
+				"{} was outside of the range",
+				number
+			);
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn ensure_non_finite_float_range_panics() {
+		let mut rng = WyRand::new();
+		rng.generate_range(0.0..f64::INFINITY);
+	}
+
+	#[test]
+	#[should_panic]
+	fn ensure_empty_float_range_panics() {
+		let mut rng = WyRand::new();
+		rng.generate_range(1.0_f32..1.0);
+	}
+
+	#[test]
+	fn ensure_chars_generate_properly() {
+		let mut rng = WyRand::new();
+		for _ in 0..1000 {
+			let c = rng.generate::<char>();
+			assert!(
+				!(0xD800..0xE000).contains(&(c as u32)),
+				"{:x} was a surrogate",
+				c as u32
+			);
+
+			let c = rng.generate_range('a'..='z');
+			assert!(c.is_ascii_lowercase(), "{} was outside of 'a'..='z'", c);
+
+			let c = rng.generate_range('0'..'5');
+			assert!(('0'..'5').contains(&c), "{} was outside of '0'..'5'", c);
+
+			// This range straddles the surrogates, which should be skipped over.
+			let c = rng.generate_range('\u{D7FF}'..='\u{E000}');
+			assert!(
+				c == '\u{D7FF}' || c == '\u{E000}',
+				"{:x} was outside of '\\u{{D7FF}}'..='\\u{{E000}}'",
+				c as u32
+			);
+		}
+	}
+
+	#[test]
+	fn ensure_composite_types_generate_properly() {
+		type Twelve = (
+			u8,
+			i8,
+			u16,
+			i16,
+			u32,
+			i32,
+			Wrapping<u64>,
+			i64,
+			u128,
+			NonZeroI128,
+			[[bool; 2]; 3],
+			(),
+		);
+		let mut rng = WyRand::new();
+		let mut somes = 0;
+		let mut oks = 0;
+		for _ in 0..1000 {
+			let (numbers, option) = rng.generate::<([u32; 4], Option<bool>)>();
+			assert_eq!(numbers.len(), 4);
+			if option.is_some() {
+				somes += 1;
+			}
+
+			let result = rng.generate::<Result<u8, char>>();
+			if result.is_ok() {
+				oks += 1;
+			}
+
+			let number = rng.generate::<NonZeroU8>();
+			assert_ne!(number.get(), 0);
+
+			let duration = rng.generate::<Duration>();
+			assert!(duration.subsec_nanos() < 1_000_000_000);
+
+			let _: Twelve = rng.generate();
+		}
+		assert!(
+			(400..600).contains(&somes),
+			"{} was too far from 500",
+			somes
+		);
+		assert!((400..600).contains(&oks), "{} was too far from 500", oks);
+	}
+
+	#[test]
+	#[allow(clippy::reversed_empty_ranges)]
+	fn ensure_invalid_ranges_error() {
+		let mut rng = WyRand::new();
+		assert_eq!(rng.try_generate_range(5_u8..5), Err(RangeError::Empty));
+		assert_eq!(rng.try_generate_range(5_u8..=4), Err(RangeError::Inverted));
+		assert_eq!(
+			rng.try_generate_range((Bound::Excluded(5_u8), Bound::Excluded(6))),
+			Err(RangeError::Empty)
+		);
+		assert_eq!(
+			rng.try_generate_range((Bound::Excluded(u64::MAX), Bound::Unbounded)),
+			Err(RangeError::Empty)
+		);
+		assert_eq!(rng.try_generate_range(..u128::MIN), Err(RangeError::Empty));
+		assert_eq!(rng.try_generate_range(-3_i32..-3), Err(RangeError::Empty));
+		assert_eq!(rng.try_generate_range(3_i64..-3), Err(RangeError::Inverted));
+		assert_eq!(rng.try_generate_range('z'..'a'), Err(RangeError::Inverted));
+		assert_eq!(rng.try_generate_range(1.0_f64..1.0), Err(RangeError::Empty));
+		assert_eq!(
+			rng.try_generate_range(2.0_f32..1.0),
+			Err(RangeError::Inverted)
+		);
+		assert_eq!(
+			rng.try_generate_range(f64::NAN..1.0),
+			Err(RangeError::NonFinite)
+		);
+		assert_eq!(
+			rng.try_generate_range(0.0_f32..),
+			Err(RangeError::NonFinite)
+		);
+	}
+
+	#[test]
+	fn ensure_full_ranges_are_inclusive() {
+		let mut rng = WyRand::new();
+		let mut seen = [false; 256];
+		for _ in 0..10000 {
+			seen[rng.generate_range(u8::MIN..=u8::MAX) as usize] = true;
+			assert_eq!(rng.generate_range(u8::MAX..), u8::MAX);
+			assert_eq!(rng.generate_range(i8::MAX..), i8::MAX);
+			assert_eq!(rng.generate_range(..=i16::MIN), i16::MIN);
+			assert_eq!(rng.generate_range(u128::MAX..), u128::MAX);
+			assert_eq!(rng.generate_range(5_u32..=5), 5);
+		}
+		assert!(seen.iter().all(|seen| *seen), "not every u8 was generated");
+	}
+
 	#[test]
 	#[should_panic]
 	fn ensure_invalid_range_panics() {
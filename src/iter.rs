@@ -0,0 +1,343 @@
+use crate::{
+	distributions::Distribution,
+	gen::{RandomGen, RandomRange},
+	Rng,
+};
+use core::{iter::FusedIterator, marker::PhantomData, ops::RangeBounds};
+
+/// A generator whose output is handed out a few bytes at a time, which the iterators in this module generate values with.
+///
+/// Values smaller than a block, like a [`u8`] from [`ChaCha`](crate::rand::ChaCha)'s 64-byte blocks,
+/// take their bytes from what's left of the last block instead of throwing it away,
+/// and requests for several blocks are passed on to the generator's [`Rng::fill_bytes`] in one go.
+pub struct BlockRng<Generator: Rng<OUTPUT>, const OUTPUT: usize> {
+	rng: Generator,
+	block: [u8; OUTPUT],
+	/// How many bytes of `block` have already been handed out.
+	used: usize,
+}
+
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> BlockRng<Generator, OUTPUT> {
+	pub(crate) const fn new(rng: Generator) -> Self {
+		Self {
+			rng,
+			block: [0; OUTPUT],
+			used: OUTPUT,
+		}
+	}
+}
+
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> Rng<OUTPUT> for BlockRng<Generator, OUTPUT> {
+	fn rand(&mut self) -> [u8; OUTPUT] {
+		if self.used == OUTPUT {
+			return self.rng.rand();
+		}
+		let mut output = [0; OUTPUT];
+		self.fill_bytes(&mut output);
+		output
+	}
+
+	fn fill_bytes<Bytes>(&mut self, mut buffer: Bytes)
+	where
+		Bytes: AsMut<[u8]>,
+	{
+		let buffer = buffer.as_mut();
+		let leftover = (OUTPUT - self.used).min(buffer.len());
+		let (start, rest) = buffer.split_at_mut(leftover);
+		start.copy_from_slice(&self.block[self.used..self.used + leftover]);
+		self.used += leftover;
+
+		// Whole blocks come straight from the generator, and the rest of the last one is kept for later.
+		let (whole, end) = rest.split_at_mut(rest.len() - rest.len() % OUTPUT);
+		if !whole.is_empty() {
+			self.rng.fill_bytes(whole);
+		}
+		if !end.is_empty() {
+			self.block = self.rng.rand();
+			end.copy_from_slice(&self.block[..end.len()]);
+			self.used = end.len();
+		}
+	}
+}
+
+impl<Generator: Rng<OUTPUT>, const OUTPUT: usize> Clone for BlockRng<Generator, OUTPUT> {
+	fn clone(&self) -> Self {
+		Self {
+			rng: self.rng.clone(),
+			block: self.block,
+			used: self.used,
+		}
+	}
+}
+
+/// A borrowed generator, which an iterator works on a [`BlockRng`] copy of.
+/// The copy takes the borrowed generator's place when the iterator is dropped.
+struct Borrowed<'a, Generator: Rng<OUTPUT>, const OUTPUT: usize> {
+	rng: &'a mut Generator,
+	blocks: BlockRng<Generator, OUTPUT>,
+}
+
+impl<'a, Generator: Rng<OUTPUT>, const OUTPUT: usize> Borrowed<'a, Generator, OUTPUT> {
+	fn new(rng: &'a mut Generator) -> Self {
+		let blocks = BlockRng::new(rng.clone());
+		Self { rng, blocks }
+	}
+}
+
+impl<'a, Generator: Rng<OUTPUT>, const OUTPUT: usize> Drop for Borrowed<'a, Generator, OUTPUT> {
+	fn drop(&mut self) {
+		core::mem::swap(self.rng, &mut self.blocks.rng);
+	}
+}
+
+/// An endless iterator of random values, created by [`Rng::iter`].
+///
+/// Values are generated lazily, through a [`BlockRng`], so generators with large blocks of output,
+/// like [`ChaCha`](crate::rand::ChaCha), have every byte of each block used.
+/// The borrowed RNG carries on after the last block the iterator used once it's dropped,
+/// and any bytes left over from that block are thrown away.
+///
+/// ```rust
+/// use nanorand::{Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let rolls: Vec<u8> = rng.iter::<u8>().map(|byte| byte % 6 + 1).take(10).collect();
+/// assert_eq!(rolls.len(), 10);
+/// ```
+pub struct Iter<'a, Generated, Generator: Rng<OUTPUT>, const OUTPUT: usize> {
+	rng: Borrowed<'a, Generator, OUTPUT>,
+	generated: PhantomData<fn() -> Generated>,
+}
+
+impl<'a, Generated, Generator: Rng<OUTPUT>, const OUTPUT: usize>
+	Iter<'a, Generated, Generator, OUTPUT>
+where
+	Generated: RandomGen<BlockRng<Generator, OUTPUT>, OUTPUT>,
+{
+	/// Creates a new iterator of random values, generated by `rng`.
+	pub fn new(rng: &'a mut Generator) -> Self {
+		Self {
+			rng: Borrowed::new(rng),
+			generated: PhantomData,
+		}
+	}
+}
+
+impl<'a, Generated, Generator: Rng<OUTPUT>, const OUTPUT: usize> Iterator
+	for Iter<'a, Generated, Generator, OUTPUT>
+where
+	Generated: RandomGen<BlockRng<Generator, OUTPUT>, OUTPUT>,
+{
+	type Item = Generated;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(Generated::random(&mut self.rng.blocks))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<'a, Generated, Generator: Rng<OUTPUT>, const OUTPUT: usize> FusedIterator
+	for Iter<'a, Generated, Generator, OUTPUT>
+where
+	Generated: RandomGen<BlockRng<Generator, OUTPUT>, OUTPUT>,
+{
+}
+
+/// An endless iterator of random values within a range, created by [`Rng::iter_range`].
+///
+/// Like [`Iter`], this generates values through a [`BlockRng`].
+///
+/// # Panics
+/// Like [`Rng::generate_range`], iterating will panic if the range doesn't contain any values,
+/// or if it's a floating point range with bounds that aren't finite.
+///
+/// ```rust
+/// use nanorand::{Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let total: u32 = rng.iter_range(1_u32..=6).take(3).sum();
+/// assert!((3..=18).contains(&total));
+/// ```
+pub struct RangeIter<'a, Generated, Bounds, Generator: Rng<OUTPUT>, const OUTPUT: usize> {
+	rng: Borrowed<'a, Generator, OUTPUT>,
+	range: Bounds,
+	generated: PhantomData<fn() -> Generated>,
+}
+
+impl<'a, Generated, Bounds, Generator: Rng<OUTPUT>, const OUTPUT: usize>
+	RangeIter<'a, Generated, Bounds, Generator, OUTPUT>
+where
+	Generated: RandomRange<BlockRng<Generator, OUTPUT>, OUTPUT>,
+	Bounds: RangeBounds<Generated> + Clone,
+{
+	/// Creates a new iterator of random values within `range`, generated by `rng`.
+	pub fn new(rng: &'a mut Generator, range: Bounds) -> Self {
+		Self {
+			rng: Borrowed::new(rng),
+			range,
+			generated: PhantomData,
+		}
+	}
+}
+
+impl<'a, Generated, Bounds, Generator: Rng<OUTPUT>, const OUTPUT: usize> Iterator
+	for RangeIter<'a, Generated, Bounds, Generator, OUTPUT>
+where
+	Generated: RandomRange<BlockRng<Generator, OUTPUT>, OUTPUT>,
+	Bounds: RangeBounds<Generated> + Clone,
+{
+	type Item = Generated;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(Generated::random_range(
+			&mut self.rng.blocks,
+			self.range.clone(),
+		))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<'a, Generated, Bounds, Generator: Rng<OUTPUT>, const OUTPUT: usize> FusedIterator
+	for RangeIter<'a, Generated, Bounds, Generator, OUTPUT>
+where
+	Generated: RandomRange<BlockRng<Generator, OUTPUT>, OUTPUT>,
+	Bounds: RangeBounds<Generated> + Clone,
+{
+}
+
+/// An endless iterator of values sampled from a [`Distribution`], created by [`Rng::sample_iter`].
+///
+/// Like [`Iter`], this generates values through a [`BlockRng`].
+///
+/// ```rust
+/// use nanorand::{distributions::Normal, Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let noise: Vec<f64> = rng.sample_iter(Normal::new(0.0, 0.1).unwrap()).take(100).collect();
+/// assert_eq!(noise.len(), 100);
+/// ```
+pub struct SampleIter<'a, Sampled, Dist, Generator: Rng<OUTPUT>, const OUTPUT: usize> {
+	rng: Borrowed<'a, Generator, OUTPUT>,
+	distribution: Dist,
+	sampled: PhantomData<fn() -> Sampled>,
+}
+
+impl<'a, Sampled, Dist, Generator: Rng<OUTPUT>, const OUTPUT: usize>
+	SampleIter<'a, Sampled, Dist, Generator, OUTPUT>
+where
+	Dist: Distribution<Sampled>,
+{
+	/// Creates a new iterator of values sampled from `distribution`, using `rng`.
+	pub fn new(rng: &'a mut Generator, distribution: Dist) -> Self {
+		Self {
+			rng: Borrowed::new(rng),
+			distribution,
+			sampled: PhantomData,
+		}
+	}
+}
+
+impl<'a, Sampled, Dist, Generator: Rng<OUTPUT>, const OUTPUT: usize> Iterator
+	for SampleIter<'a, Sampled, Dist, Generator, OUTPUT>
+where
+	Dist: Distribution<Sampled>,
+{
+	type Item = Sampled;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.distribution.sample(&mut self.rng.blocks))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<'a, Sampled, Dist, Generator: Rng<OUTPUT>, const OUTPUT: usize> FusedIterator
+	for SampleIter<'a, Sampled, Dist, Generator, OUTPUT>
+where
+	Dist: Distribution<Sampled>,
+{
+}
+
+#[cfg(all(test, feature = "wyrand"))]
+mod tests {
+	use super::BlockRng;
+	use crate::{distributions::Bernoulli, Rng, WyRand};
+
+	#[test]
+	fn ensure_iterators_match_single_calls() {
+		let mut rng = WyRand::new_seed(42);
+		// Each iterator uses a whole number of blocks, so none are left over when it's dropped.
+		let mut other = BlockRng::new(rng.clone());
+		let iterated: Vec<u64> = rng.iter().take(100).collect();
+		let generated: Vec<u64> = (0..100).map(|_| other.generate()).collect();
+		assert_eq!(iterated, generated);
+
+		let iterated: Vec<u16> = rng.iter_range(10..=20).take(100).collect();
+		let generated: Vec<u16> = (0..100).map(|_| other.generate_range(10..=20)).collect();
+		assert_eq!(iterated, generated);
+		assert!(iterated.iter().all(|n| (10..=20).contains(n)));
+
+		let coin = Bernoulli::new(0.25).unwrap();
+		let iterated: Vec<bool> = rng.sample_iter(coin).take(100).collect();
+		let sampled: Vec<bool> = (0..100).map(|_| other.sample(coin)).collect();
+		assert_eq!(iterated, sampled);
+
+		// The RNG is only borrowed, so it carries on where the iterator left off.
+		assert_eq!(rng.generate::<u64>(), other.generate::<u64>());
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn ensure_buffered_iterators_use_whole_blocks() {
+		let mut rng = crate::BufferedRng::new(WyRand::new_seed(42));
+		let bytes: Vec<u8> = rng.iter().take(16).collect();
+		let mut expected = [0_u8; 16];
+		WyRand::new_seed(42).fill_bytes(&mut expected);
+		assert_eq!(bytes, expected);
+	}
+
+	#[test]
+	#[cfg(feature = "chacha")]
+	fn ensure_chacha_iterators_use_every_byte() {
+		use crate::ChaCha20;
+
+		let mut rng = ChaCha20::new_key([42; 32], [0; 8], [0; 8]);
+		let mut expected = [0_u8; 64 * 5];
+		rng.clone().fill_bytes(&mut expected);
+
+		let bytes: Vec<u8> = rng.iter().take(200).collect();
+		assert_eq!(bytes[..], expected[..200]);
+		// The rest of the fourth block is thrown away with the iterator.
+		assert_eq!(rng.get_word_pos(), 4 * 16);
+		assert_eq!(rng.rand()[..], expected[256..]);
+
+		// Values that straddle two blocks take the end of one and the start of the next.
+		let mut rng = ChaCha20::new_key([42; 32], [0; 8], [0; 8]);
+		let pairs: Vec<(u8, u64)> = rng.iter().take(30).collect();
+		for (index, (byte, word)) in pairs.into_iter().enumerate() {
+			let start = index * 9;
+			assert_eq!(byte, expected[start]);
+			let mut bytes = [0; 8];
+			bytes.copy_from_slice(&expected[start + 1..start + 9]);
+			assert_eq!(word, u64::from_le_bytes(bytes));
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn ensure_empty_ranges_panic() {
+		let mut rng = WyRand::new_seed(42);
+		rng.iter_range(5_u8..5).next();
+	}
+}
@@ -26,6 +26,14 @@
 //! let mut rng = nanorand::tls_rng();
 //! println!("Random number: {}", rng.generate::<u64>());
 //! ```
+//! ## Generating reproducible numbers from a seed
+//! ```rust
+//! use nanorand::{Rng, SeedableRng, WyRand};
+//!
+//! // Every seedable RNG can be created from a `u64`, which always gives the same numbers.
+//! let mut rng = WyRand::seed_from_u64(42);
+//! println!("Random number: {}", rng.generate::<u64>());
+//! ```
 //! ## Generating a number in a range
 //! ```rust
 //! use nanorand::{Rng, WyRand};
@@ -33,6 +41,15 @@
 //! let mut rng = WyRand::new();
 //! println!("Random number between 1 and 100: {}", rng.generate_range(1_u64..=100));
 //! println!("Random number between -100 and 50: {}", rng.generate_range(-100_i64..=50));
+//! println!("Random float between 0.5 and 2.0: {}", rng.generate_range(0.5_f64..2.0));
+//! ```
+//! ## Generating many numbers
+//! ```rust
+//! use nanorand::{Rng, WyRand};
+//!
+//! let mut rng = WyRand::new();
+//! let rolls: Vec<u8> = rng.iter_range(1..=6).take(10).collect();
+//! println!("Ten dice rolls: {:?}", rolls);
 //! ```
 //! ### Buffering random bytes
 //! ```rust
@@ -45,6 +62,30 @@
 //! // 3 bytes will remain in the buffer.
 //! assert_eq!(rng.buffered(), 3);
 //! ```
+//! ## Generating a random string
+//! ```rust
+//! use nanorand::{Rng, WyRand};
+//!
+//! let mut rng = WyRand::new();
+//! println!("Random token: {}", rng.alphanumeric(32));
+//! println!("Random PIN: {}", rng.string_from_alphabet("0123456789", 6));
+//! ```
+//! ## Sampling from a probability distribution
+//! ```rust
+//! use nanorand::{distributions::Normal, Rng, WyRand};
+//!
+//! let mut rng = WyRand::new();
+//! let heights = Normal::new(170.0, 10.0).unwrap();
+//! println!("Random height: {:.1}cm", rng.sample(&heights));
+//! ```
+//! ## Flipping a biased coin
+//! ```rust
+//! use nanorand::{Rng, WyRand};
+//!
+//! let mut rng = WyRand::new();
+//! println!("Rolled out to you: {}", rng.gen_bool(0.05));
+//! println!("One in three: {}", rng.gen_ratio(1, 3));
+//! ```
 //! ## Shuffling a Vec
 //! ```rust
 //! use nanorand::{Rng, WyRand};
@@ -53,6 +94,15 @@
 //! let mut items = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
 //! rng.shuffle(&mut items);
 //! ```
+//! ## Picking random items
+//! ```rust
+//! use nanorand::{Rng, WyRand};
+//!
+//! let mut rng = WyRand::new();
+//! let items = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+//! println!("Random item: {}", rng.choose(&items).unwrap());
+//! println!("Three random items: {:?}", rng.choose_multiple(&items, 3));
+//! ```
 //!
 //! ## Why should I use this over...
 //!
@@ -65,8 +115,13 @@
 //! **RNG**|**nanorand type**|**Output Size**|**Cryptographically Secure**|**Speed**<sup>1</sup>|**Notes**|**Original Implementation**
 //! :-----:|:-----:|:-----:|:-----:|:-----:|:-----:|:-----:
 //! wyrand|[`nanorand::WyRand`](rand/wyrand/struct.WyRand.html), [`nanorand::tls::TlsWyRand`](tls/fn.tls_rng.html)|64 bits (`u64`)|🚫|14 GB/s||[https://github.com/lemire/testingRNG/blob/master/source/wyrand.h](https://github.com/lemire/testingRNG/blob/master/source/wyrand.h)
-//! Pcg64|[`nanorand::Pcg64`](rand/pcg64/struct.Pcg64.html)|64 bits (`u64`)|🚫|1.6 GB/s||[https://github.com/rkern/pcg64](https://github.com/rkern/pcg64)
-//! ChaCha|[`nanorand::ChaCha`](rand/chacha/struct.ChaCha.html)|512 bits (`[u32; 16]`)|✅|980 MB/s (ChaCha8), 749 MB/s (ChaCha12), 505 MB/s (ChaCha20)||[https://cr.yp.to/chacha.html](https://cr.yp.to/chacha.html)
+//! Pcg32|[`nanorand::Pcg32`](rand/pcg32/struct.Pcg32.html)|32 bits (`u32`)|🚫|||[https://github.com/imneme/pcg-c](https://github.com/imneme/pcg-c)
+//! Pcg64|[`nanorand::Pcg64`](rand/pcg64/struct.Pcg64.html), [`nanorand::Pcg64Mcg`](rand/pcg64/struct.Pcg64Mcg.html), [`nanorand::Pcg64Dxsm`](rand/pcg64/struct.Pcg64Dxsm.html)|64 bits (`u64`)|🚫|||[https://github.com/imneme/pcg-cpp](https://github.com/imneme/pcg-cpp)
+//! Pcg64Legacy|[`nanorand::Pcg64Legacy`](rand/pcg64_legacy/struct.Pcg64Legacy.html)|64 bits (`u64`)|🚫|1.6 GB/s|`nanorand::Pcg64` up to 0.8, doesn't match reference PCG|[https://github.com/rkern/pcg64](https://github.com/rkern/pcg64)
+//! xoshiro/xoroshiro|[`nanorand::Xoshiro256PlusPlus`](rand/xoshiro256plusplus/type.Xoshiro256PlusPlus.html), [`nanorand::Xoshiro256StarStar`](rand/xoshiro256starstar/type.Xoshiro256StarStar.html), [`nanorand::Xoroshiro128PlusPlus`](rand/xoroshiro128plusplus/struct.Xoroshiro128PlusPlus.html)|64 bits (`u64`)|🚫|||[https://prng.di.unimi.it/](https://prng.di.unimi.it/)
+//! xoshiro128++|[`nanorand::Xoshiro128PlusPlus`](rand/xoshiro128plusplus/struct.Xoshiro128PlusPlus.html)|32 bits (`u32`)|🚫||For 32-bit targets|[https://prng.di.unimi.it/](https://prng.di.unimi.it/)
+//! SplitMix64|[`nanorand::SplitMix64`](rand/splitmix64/struct.SplitMix64.html)|64 bits (`u64`)|🚫||Used by `seed_from_u64`|[https://prng.di.unimi.it/](https://prng.di.unimi.it/)
+//! ChaCha|[`nanorand::ChaCha`](rand/chacha/struct.ChaCha.html)|512 bits (`[u32; 16]`)|✅|980 MB/s (ChaCha8), 749 MB/s (ChaCha12), 505 MB/s (ChaCha20)|Original, IETF (RFC 8439) and XChaCha layouts|[https://cr.yp.to/chacha.html](https://cr.yp.to/chacha.html)
 //!
 //! <sup>1. Speed benchmarked on an M1 Macbook Air</sup>
 //!
@@ -85,16 +140,19 @@
 //!
 //! ## Feature Flags
 //!
-//! * `alloc` (default) - Enables Rust `alloc` lib features, such as a buffering Rng wrapper.
+//! * `alloc` (default) - Enables Rust `alloc` lib features, such as a buffering Rng wrapper and random string generation.
 //! * `entropy` (default) - Allows sourcing entropy from the system. Implied by `getrandom`, too.
 //! * `std` (default) - Enables Rust `std` lib features, such as seeding from OS entropy sources. Requires `alloc` to be enabled.
 //! * `tls` (default) - Enables a thread-local [`WyRand`](rand/wyrand/struct.WyRand.html) RNG (see below). Requires `std` to be enabled.
 //! * `wyrand` (default) - Enable the [`WyRand`](rand/wyrand/struct.WyRand.html) RNG.
-//! * `pcg64` (default) - Enable the [`Pcg64`](rand/pcg64/struct.Pcg64.html)  RNG.
+//! * `pcg32` (default) - Enable the [`Pcg32`](rand/pcg32/struct.Pcg32.html) RNG.
+//! * `pcg64` (default) - Enable the [`Pcg64`](rand/pcg64/struct.Pcg64.html), [`Pcg64Mcg`](rand/pcg64/struct.Pcg64Mcg.html), [`Pcg64Dxsm`](rand/pcg64/struct.Pcg64Dxsm.html) and [`Pcg64Legacy`](rand/pcg64_legacy/struct.Pcg64Legacy.html) RNGs.
+//! * `xoshiro256plusplus`, `xoshiro256starstar`, `xoroshiro128plusplus`, `xoshiro128plusplus` - Enable the [`Xoshiro256PlusPlus`](rand/xoshiro256plusplus/type.Xoshiro256PlusPlus.html), [`Xoshiro256StarStar`](rand/xoshiro256starstar/type.Xoshiro256StarStar.html), [`Xoroshiro128PlusPlus`](rand/xoroshiro128plusplus/struct.Xoroshiro128PlusPlus.html) and [`Xoshiro128PlusPlus`](rand/xoshiro128plusplus/struct.Xoshiro128PlusPlus.html) RNGs, respectively. `xoshiro` enables all of them.
 //! * `chacha` - Enable the [`ChaCha`](rand/chacha/struct.ChaCha.html) RNG. Requires Rust 1.47 or later.
 //! * `rdseed` - On x86 and x86-64 platforms, the `rdseed` intrinsic will be used when OS entropy isn't available.
 //! * `zeroize` - Implement the [Zeroize](https://crates.io/crates/zeroize) trait for all RNGs.
 //! * `getrandom` - Use the [`getrandom`](https://crates.io/crates/getrandom) crate as an entropy source. Works on most systems, optional due to the fact that it brings in more dependencies.
+//! * `derive` - Enables `#[derive(RandomGen)]` for structs and enums, through the `nanorand-derive` crate.
 //!
 //! ## MSRV
 //! The minimum supported Rust version for the latest version of nanorand is **Rust 1.56.0**, released October 21st, 2021.
@@ -105,22 +163,34 @@ extern crate alloc;
 #[cfg(feature = "alloc")]
 pub use buffer::BufferedRng;
 pub use gen::*;
+#[cfg(feature = "derive")]
+pub use nanorand_derive::RandomGen;
 pub use rand::*;
 #[cfg(feature = "tls")]
 pub use tls::tls_rng;
 
+#[cfg(feature = "alloc")]
+/// Character sets for generating random strings.
+pub mod alphabet;
 #[cfg(feature = "alloc")]
 /// Provides a buffered wrapper for RNGs, preventing bits from being wasted.
 pub mod buffer;
 /// Implementation of cryptography, for CSPRNGs.
 pub mod crypto;
+/// Probability distributions, for sampling non-uniform values.
+pub mod distributions;
 /// Sources for obtaining entropy.
 #[cfg(any(feature = "entropy", feature = "getrandom"))]
 pub mod entropy;
 /// Traits for generating types from an RNG.
 pub mod gen;
+/// Endless iterators of random values.
+pub mod iter;
+mod math;
 /// RNG algorithms.
 pub mod rand;
+/// Tools for shuffling and sampling sequences.
+pub mod seq;
 #[cfg(feature = "tls")]
 /// Provides a thread-local [`WyRand`] RNG.
 pub mod tls;
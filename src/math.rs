@@ -0,0 +1,438 @@
+// `core` doesn't have most floating point functions, as they usually come from the system's libm,
+// so without `std`, we use ports of the ones from musl (which are in turn based off FreeBSD's msun).
+
+#[cfg(feature = "std")]
+pub(crate) fn exp(x: f64) -> f64 {
+	x.exp()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn exp(x: f64) -> f64 {
+	soft::exp(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn ln(x: f64) -> f64 {
+	x.ln()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn ln(x: f64) -> f64 {
+	soft::ln(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sqrt(x: f64) -> f64 {
+	x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sqrt(x: f64) -> f64 {
+	soft::sqrt(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+	x.powf(y)
+}
+
+/// This is only accurate for positive `x`, which is all we need it for.
+#[cfg(not(feature = "std"))]
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+	soft::exp(y * soft::ln(x))
+}
+
+pub(crate) fn abs(x: f64) -> f64 {
+	f64::from_bits(x.to_bits() & !(1 << 63))
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn floor(x: f64) -> f64 {
+	x.floor()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn floor(x: f64) -> f64 {
+	// Anything this large (or NaN/infinite) is already a whole number.
+	if x.is_nan() || abs(x) >= 4503599627370496.0 {
+		return x;
+	}
+	let truncated = x as i64 as f64;
+	if truncated > x {
+		truncated - 1.0
+	} else {
+		truncated
+	}
+}
+
+/// Computes `ln(1 + x)`, accurately even when `x` is close to zero.
+#[cfg(feature = "std")]
+pub(crate) fn ln_1p(x: f64) -> f64 {
+	x.ln_1p()
+}
+
+/// Computes `ln(1 + x)`, accurately even when `x` is close to zero.
+#[cfg(not(feature = "std"))]
+pub(crate) fn ln_1p(x: f64) -> f64 {
+	soft::ln_1p(x)
+}
+
+/// Computes `e^x - 1`, accurately even when `x` is close to zero.
+#[cfg(feature = "std")]
+pub(crate) fn exp_m1(x: f64) -> f64 {
+	x.exp_m1()
+}
+
+/// Computes `e^x - 1`, accurately even when `x` is close to zero.
+#[cfg(not(feature = "std"))]
+pub(crate) fn exp_m1(x: f64) -> f64 {
+	soft::exp_m1(x)
+}
+
+/// `ln(2 * pi)`
+pub(crate) const LN_2PI: f64 = 1.8378770664093456;
+
+/// The error of Stirling's approximation, `ln(n!) - ((n + 0.5) * ln(n) - n + ln(2 * pi) / 2)`, for `n >= 1`.
+///
+/// Along with [`deviance`], this is used to evaluate probability mass functions without catastrophic cancellation,
+/// as described by Catherine Loader in "Fast and Accurate Computation of Binomial Probabilities".
+pub(crate) fn stirling_error(n: f64) -> f64 {
+	const SMALL: [f64; 16] = [
+		0.0,
+		0.08106146679532726,
+		0.0413406959554093,
+		0.02767792568499834,
+		0.020790672103765093,
+		0.016644691189821193,
+		0.013876128823070748,
+		0.01189670994589177,
+		0.010411265261972096,
+		0.009255462182712733,
+		0.00833056343336287,
+		0.007573675487951841,
+		0.00694284010720953,
+		0.006408994188004207,
+		0.0059513701127588475,
+		0.005554733551962801,
+	];
+	const S0: f64 = 1.0 / 12.0;
+	const S1: f64 = 1.0 / 360.0;
+	const S2: f64 = 1.0 / 1260.0;
+	const S3: f64 = 1.0 / 1680.0;
+	const S4: f64 = 1.0 / 1188.0;
+
+	if n < 16.0 {
+		return SMALL[n as usize];
+	}
+	let nn = n * n;
+	if n > 500.0 {
+		(S0 - S1 / nn) / n
+	} else if n > 80.0 {
+		(S0 - (S1 - S2 / nn) / nn) / n
+	} else if n > 35.0 {
+		(S0 - (S1 - (S2 - S3 / nn) / nn) / nn) / n
+	} else {
+		(S0 - (S1 - (S2 - (S3 - S4 / nn) / nn) / nn) / nn) / n
+	}
+}
+
+/// The deviance term, `x * ln(x / mean) + mean - x`, computed accurately even when `x` is close to `mean`.
+pub(crate) fn deviance(x: f64, mean: f64) -> f64 {
+	if x == 0.0 {
+		// The limit of `x * ln(x / mean)` is 0, even when `mean` is 0 too.
+		mean
+	} else if abs(x - mean) < 0.1 * (x + mean) {
+		// Use the series expansion, as both terms nearly cancel out.
+		let mut v = (x - mean) / (x + mean);
+		let mut sum = (x - mean) * v;
+		let mut term = 2.0 * x * v;
+		v *= v;
+		for j in 1..1000 {
+			term *= v;
+			let next = sum + term / f64::from(2 * j + 1);
+			if next == sum {
+				break;
+			}
+			sum = next;
+		}
+		sum
+	} else {
+		x * ln(x / mean) + mean - x
+	}
+}
+
+/// The natural logarithm of the binomial probability mass function, `ln(P(X = x))` for `X ~ Binomial(n, p)`,
+/// where `q = 1 - p` is passed separately to avoid losing precision.
+pub(crate) fn ln_binomial_pmf(x: f64, n: f64, p: f64, q: f64) -> f64 {
+	if x == 0.0 {
+		return if p < 0.1 {
+			-deviance(n, n * q) - n * p
+		} else {
+			n * ln(q)
+		};
+	}
+	if x == n {
+		return if q < 0.1 {
+			-deviance(n, n * p) - n * q
+		} else {
+			n * ln(p)
+		};
+	}
+	let lc = stirling_error(n)
+		- stirling_error(x)
+		- stirling_error(n - x)
+		- deviance(x, n * p)
+		- deviance(n - x, n * q);
+	let lf = LN_2PI + ln(x) + ln_1p(-x / n);
+	lc - 0.5 * lf
+}
+
+// The constants are copied verbatim from musl, so leave their precision as-is.
+#[cfg(any(not(feature = "std"), test))]
+#[allow(clippy::excessive_precision)]
+mod soft {
+	const LN2_HIGH: f64 = 6.93147180369123816490e-01;
+	const LN2_LOW: f64 = 1.90821492927058770002e-10;
+
+	/// Multiplies `x` by 2 to the power of `n`.
+	fn scalbn(x: f64, mut n: i32) -> f64 {
+		let x1p1023 = f64::from_bits(0x7fe0000000000000);
+		let x1p53 = f64::from_bits(0x4340000000000000);
+		let x1p_1022 = f64::from_bits(0x0010000000000000);
+		let mut y = x;
+		if n > 1023 {
+			y *= x1p1023;
+			n -= 1023;
+			if n > 1023 {
+				y *= x1p1023;
+				n -= 1023;
+				if n > 1023 {
+					n = 1023;
+				}
+			}
+		} else if n < -1022 {
+			y *= x1p_1022 * x1p53;
+			n += 1022 - 53;
+			if n < -1022 {
+				y *= x1p_1022 * x1p53;
+				n += 1022 - 53;
+				if n < -1022 {
+					n = -1022;
+				}
+			}
+		}
+		y * f64::from_bits(((0x3ff + n) as u64) << 52)
+	}
+
+	pub(crate) fn exp(mut x: f64) -> f64 {
+		const HALF: [f64; 2] = [0.5, -0.5];
+		const P1: f64 = 1.66666666666666019037e-01;
+		const P2: f64 = -2.77777777770155933842e-03;
+		const P3: f64 = 6.61375632143793436117e-05;
+		const P4: f64 = -1.65339022054652515390e-06;
+		const P5: f64 = 4.13813679705723846039e-08;
+
+		let mut high_word = (x.to_bits() >> 32) as u32;
+		let sign = (high_word >> 31) as usize;
+		high_word &= 0x7fffffff;
+
+		// Special cases, like overflow, underflow, and NaN.
+		if high_word >= 0x4086232b {
+			if x.is_nan() {
+				return x;
+			}
+			if x > 709.782712893383973096 {
+				return f64::INFINITY;
+			}
+			if x < -745.13321910194110842 {
+				return 0.0;
+			}
+		}
+
+		// Reduce the argument, so x = k*ln2 + r, where |r| <= 0.5*ln2.
+		let k: i32;
+		let high: f64;
+		let low: f64;
+		if high_word > 0x3fd62e42 {
+			k = if high_word >= 0x3ff0a2b2 {
+				(core::f64::consts::LOG2_E * x + HALF[sign]) as i32
+			} else {
+				1 - sign as i32 - sign as i32
+			};
+			high = x - k as f64 * LN2_HIGH;
+			low = k as f64 * LN2_LOW;
+			x = high - low;
+		} else if high_word > 0x3e300000 {
+			k = 0;
+			high = x;
+			low = 0.0;
+		} else {
+			return 1.0 + x;
+		}
+
+		let xx = x * x;
+		let c = x - xx * (P1 + xx * (P2 + xx * (P3 + xx * (P4 + xx * P5))));
+		let y = 1.0 + (x * c / (2.0 - c) - low + high);
+		if k == 0 {
+			y
+		} else {
+			scalbn(y, k)
+		}
+	}
+
+	pub(crate) fn ln(mut x: f64) -> f64 {
+		const LG1: f64 = 6.666666666666735130e-01;
+		const LG2: f64 = 3.999999999940941908e-01;
+		const LG3: f64 = 2.857142874366239149e-01;
+		const LG4: f64 = 2.222219843214978396e-01;
+		const LG5: f64 = 1.818357216161805012e-01;
+		const LG6: f64 = 1.531383769920937332e-01;
+		const LG7: f64 = 1.479819860511658591e-01;
+
+		let x1p54 = f64::from_bits(0x4350000000000000);
+		let mut bits = x.to_bits();
+		let mut high_word = (bits >> 32) as u32;
+		let mut k: i32 = 0;
+
+		if high_word < 0x00100000 || (high_word >> 31) != 0 {
+			if bits << 1 == 0 {
+				// ln(+-0) = -inf
+				return f64::NEG_INFINITY;
+			}
+			if high_word >> 31 != 0 {
+				// ln(x < 0) = NaN
+				return f64::NAN;
+			}
+			// Scale subnormals up into the normal range.
+			k -= 54;
+			x *= x1p54;
+			bits = x.to_bits();
+			high_word = (bits >> 32) as u32;
+		} else if high_word >= 0x7ff00000 {
+			return x;
+		} else if high_word == 0x3ff00000 && bits << 32 == 0 {
+			return 0.0;
+		}
+
+		// Reduce x into [sqrt(2)/2, sqrt(2)].
+		high_word += 0x3ff00000 - 0x3fe6a09e;
+		k += (high_word >> 20) as i32 - 0x3ff;
+		high_word = (high_word & 0x000fffff) + 0x3fe6a09e;
+		bits = ((high_word as u64) << 32) | (bits & 0xffffffff);
+		x = f64::from_bits(bits);
+
+		let f = x - 1.0;
+		let half_f_squared = 0.5 * f * f;
+		let s = f / (2.0 + f);
+		let z = s * s;
+		let w = z * z;
+		let t1 = w * (LG2 + w * (LG4 + w * LG6));
+		let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+		let r = t2 + t1;
+		let k = k as f64;
+		s * (half_f_squared + r) + k * LN2_LOW - half_f_squared + f + k * LN2_HIGH
+	}
+
+	pub(crate) fn ln_1p(x: f64) -> f64 {
+		// Kahan's trick: the rounding error in computing 1 + x cancels out in the ratio.
+		let u = 1.0 + x;
+		if u == 1.0 {
+			x
+		} else {
+			ln(u) * x / (u - 1.0)
+		}
+	}
+
+	pub(crate) fn exp_m1(x: f64) -> f64 {
+		// Same as above, but in reverse.
+		let u = exp(x);
+		if u == 1.0 {
+			x
+		} else if u - 1.0 == -1.0 {
+			-1.0
+		} else {
+			(u - 1.0) * x / ln(u)
+		}
+	}
+
+	pub(crate) fn sqrt(x: f64) -> f64 {
+		if x < 0.0 {
+			return f64::NAN;
+		}
+		if x == 0.0 || !x.is_finite() {
+			return x;
+		}
+		if x < f64::MIN_POSITIVE {
+			// Scale subnormals up, so the initial guess below is reasonable.
+			return sqrt(x * f64::from_bits(0x46b0000000000000))
+				* f64::from_bits(0x3c90000000000000);
+		}
+		// Halving the exponent gets us within ~6% of the answer,
+		// and then each iteration of Newton's method roughly doubles the number of correct bits.
+		let mut y = f64::from_bits((x.to_bits() >> 1) + (0x3ff << 51));
+		for _ in 0..5 {
+			y = 0.5 * (y + x / y);
+		}
+		y
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::soft;
+
+	fn assert_close(ours: f64, expected: f64) {
+		if expected.is_nan() {
+			assert!(ours.is_nan(), "{} should have been NaN", ours);
+		} else if expected == 0.0 || expected.is_infinite() {
+			assert_eq!(ours, expected);
+		} else {
+			let error = ((ours - expected) / expected).abs();
+			assert!(
+				error <= 4.0 * f64::EPSILON,
+				"{} was too far from {}",
+				ours,
+				expected
+			);
+		}
+	}
+
+	#[test]
+	fn ensure_soft_float_functions_are_accurate() {
+		let mut inputs = vec![
+			0.0,
+			-0.0,
+			1.0,
+			-1.0,
+			0.5,
+			2.0,
+			1e-300,
+			5e-324,
+			1e300,
+			f64::MAX,
+			f64::MIN_POSITIVE,
+			f64::INFINITY,
+			f64::NEG_INFINITY,
+			f64::NAN,
+			709.0,
+			-744.0,
+			-800.0,
+			800.0,
+		];
+		let mut x = 1e-20_f64;
+		while x < 1e20 {
+			inputs.push(x);
+			inputs.push(-x);
+			x *= 1.37;
+		}
+		for x in inputs {
+			assert_close(soft::exp(x), x.exp());
+			assert_close(soft::ln(x), x.ln());
+			assert_close(soft::sqrt(x), x.sqrt());
+			if x.is_finite() && x < 700.0 {
+				assert_close(soft::ln_1p(x), x.ln_1p());
+				assert_close(soft::exp_m1(x), x.exp_m1());
+			}
+		}
+	}
+}
@@ -1,11 +1,33 @@
 #[cfg(feature = "chacha")]
 pub use chacha::{ChaCha, ChaCha12, ChaCha20, ChaCha8};
+#[cfg(feature = "pcg32")]
+pub use pcg32::Pcg32;
 #[cfg(feature = "pcg64")]
-pub use pcg64::Pcg64;
+pub use pcg64::{Pcg64, Pcg64Dxsm, Pcg64Mcg};
+#[cfg(feature = "pcg64")]
+pub use pcg64_legacy::Pcg64Legacy;
+pub use splitmix64::SplitMix64;
 #[cfg(feature = "wyrand")]
 pub use wyrand::WyRand;
+#[cfg(feature = "xoroshiro128plusplus")]
+pub use xoroshiro128plusplus::Xoroshiro128PlusPlus;
+#[cfg(feature = "xoshiro128plusplus")]
+pub use xoshiro128plusplus::Xoshiro128PlusPlus;
+#[cfg(feature = "xoshiro256plusplus")]
+pub use xoshiro256plusplus::Xoshiro256PlusPlus;
+#[cfg(feature = "xoshiro256starstar")]
+pub use xoshiro256starstar::Xoshiro256StarStar;
 
-use crate::gen::{RandomGen, RandomRange};
+#[cfg(feature = "alloc")]
+use crate::seq::ShuffledIter;
+use crate::{
+	distributions::{Bernoulli, Distribution},
+	gen::{OpenClosed01, RandomGen, RandomRange, RangeError},
+	iter::{BlockRng, Iter, RangeIter, SampleIter},
+	math,
+};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 use core::ops::RangeBounds;
 
 /// Implementation of the wyrand PRNG algorithm.
@@ -13,11 +35,49 @@ use core::ops::RangeBounds;
 #[cfg(feature = "wyrand")]
 pub mod wyrand;
 
-/// Implementation of the Pcg64 PRNG algorithm.
+/// Implementation of the Pcg32 PRNG algorithm.
+/// More details can be seen at <https://www.pcg-random.org/index.html>
+#[cfg(feature = "pcg32")]
+pub mod pcg32;
+
+/// Implementations of the 128-bit PCG PRNG algorithms.
 /// More details can be seen at <https://www.pcg-random.org/index.html>
 #[cfg(feature = "pcg64")]
 pub mod pcg64;
 
+/// Implementation of the PRNG which nanorand called `Pcg64` up to version 0.8.
+#[cfg(feature = "pcg64")]
+pub mod pcg64_legacy;
+
+/// Implementation of the SplitMix64 PRNG algorithm.
+/// More details can be seen at <https://prng.di.unimi.it/splitmix64.c>
+pub mod splitmix64;
+
+/// Implementation of the xoroshiro128++ PRNG algorithm.
+/// More details can be seen at <https://prng.di.unimi.it/>
+#[cfg(feature = "xoroshiro128plusplus")]
+pub mod xoroshiro128plusplus;
+
+/// Implementation of the xoshiro128++ PRNG algorithm.
+/// More details can be seen at <https://prng.di.unimi.it/>
+#[cfg(feature = "xoshiro128plusplus")]
+pub mod xoshiro128plusplus;
+
+/// The state transition, jumps and seeding shared by the xoshiro256 generators.
+/// More details can be seen at <https://prng.di.unimi.it/>
+#[cfg(any(feature = "xoshiro256plusplus", feature = "xoshiro256starstar"))]
+pub mod xoshiro256;
+
+/// Implementation of the xoshiro256++ PRNG algorithm.
+/// More details can be seen at <https://prng.di.unimi.it/>
+#[cfg(feature = "xoshiro256plusplus")]
+pub mod xoshiro256plusplus;
+
+/// Implementation of the xoshiro256** PRNG algorithm.
+/// More details can be seen at <https://prng.di.unimi.it/>
+#[cfg(feature = "xoshiro256starstar")]
+pub mod xoshiro256starstar;
+
 /// Implementation of the ChaCha CSPRNG algorithm.
 /// More details can be seen at <https://en.wikipedia.org/wiki/Salsa20>
 #[cfg(feature = "chacha")]
@@ -26,6 +86,9 @@ pub mod chacha;
 /// A trait that represents a random number generator.
 pub trait Rng<const OUTPUT: usize>: Clone {
 	/// Generates a random sequence of bytes, seeding from the internal state.
+	///
+	/// Generators serialize their output as little-endian, so a seeded generator produces the same bytes,
+	/// and the same [`Rng::generate`] results, on every target.
 	fn rand(&mut self) -> [u8; OUTPUT];
 
 	/// Generates a random of the specified type, seeding from the internal state.
@@ -62,7 +125,11 @@ pub trait Rng<const OUTPUT: usize>: Clone {
 		target.iter_mut().for_each(|entry| *entry = self.generate());
 	}
 
-	/// Generates a random of the specified type, seeding from the internal state.
+	/// Generates a random of the specified type within a range, seeding from the internal state.
+	///
+	/// # Panics
+	/// This function will panic if the range doesn't contain any values,
+	/// or if it's a floating point range with bounds that aren't finite.
 	fn generate_range<Number, Bounds>(&mut self, range: Bounds) -> Number
 	where
 		Number: RandomRange<Self, OUTPUT>,
@@ -71,22 +138,606 @@ pub trait Rng<const OUTPUT: usize>: Clone {
 		Number::random_range(self, range)
 	}
 
+	/// Generates a random of the specified type within a range, seeding from the internal state.
+	/// Unlike [`Rng::generate_range`], this returns an error instead of panicking on invalid ranges.
+	///
+	/// ```rust
+	/// use nanorand::{RangeError, Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// assert!(rng.try_generate_range(1_u8..=6).is_ok());
+	/// assert_eq!(rng.try_generate_range(5_u8..5), Err(RangeError::Empty));
+	/// assert_eq!(rng.try_generate_range(10_u8..=5), Err(RangeError::Inverted));
+	/// ```
+	///
+	/// # Errors
+	/// This function will return a [`RangeError`] if the range doesn't contain any values,
+	/// or if it's a floating point range with bounds that aren't finite.
+	fn try_generate_range<Number, Bounds>(&mut self, range: Bounds) -> Result<Number, RangeError>
+	where
+		Number: RandomRange<Self, OUTPUT>,
+		Bounds: RangeBounds<Number>,
+	{
+		Number::try_random_range(self, range)
+	}
+
+	/// Generates a [`bool`] which is `true` with probability `p`, seeding from the internal state.
+	///
+	/// This is exact down to the precision of `p`, or 2^-64, whichever is coarser.
+	/// If you're using the same probability over and over, a [`Bernoulli`] works this out ahead of time.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// if rng.gen_bool(0.05) {
+	///     println!("You got the new feature!");
+	/// }
+	/// assert!(rng.gen_bool(1.0));
+	/// assert!(!rng.gen_bool(0.0));
+	/// ```
+	///
+	/// # Panics
+	/// This function will panic if `p` isn't within `[0, 1]`.
+	#[inline]
+	fn gen_bool(&mut self, p: f64) -> bool {
+		match Bernoulli::new(p) {
+			Ok(bernoulli) => bernoulli.sample(self),
+			Err(_) => panic!("probability {} isn't within [0, 1]", p),
+		}
+	}
+
+	/// Generates a [`bool`] which is `true` with probability `numerator / denominator`, seeding from the internal state.
+	/// Unlike [`Rng::gen_bool`], there's no rounding, so this is exact for any ratio.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// if rng.gen_ratio(1, 3) {
+	///     println!("One in three");
+	/// }
+	/// ```
+	///
+	/// # Panics
+	/// This function will panic if `denominator` is zero, or `numerator` is greater than `denominator`.
+	#[inline]
+	fn gen_ratio(&mut self, numerator: u64, denominator: u64) -> bool {
+		assert!(
+			denominator != 0 && numerator <= denominator,
+			"{} / {} isn't a valid probability",
+			numerator,
+			denominator
+		);
+		self.generate_range(0..denominator) < numerator
+	}
+
+	/// Samples a value from a probability [`Distribution`], seeding from the internal state.
+	///
+	/// ```rust
+	/// use nanorand::{distributions::Exponential, Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let wait = rng.sample(Exponential::new(0.5).unwrap());
+	/// assert!(wait > 0.0);
+	/// ```
+	fn sample<Sampled, Dist>(&mut self, distribution: Dist) -> Sampled
+	where
+		Dist: Distribution<Sampled>,
+	{
+		distribution.sample(self)
+	}
+
+	/// Returns an endless iterator of random values of the specified type, which borrows this RNG.
+	///
+	/// Values smaller than the RNG's output share its blocks, rather than each using up a whole one;
+	/// see [`BlockRng`] for the details.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let ids: Vec<u64> = rng.iter().take(5).collect();
+	/// assert_eq!(ids.len(), 5);
+	/// ```
+	fn iter<Generated>(&mut self) -> Iter<'_, Generated, Self, OUTPUT>
+	where
+		Generated: RandomGen<BlockRng<Self, OUTPUT>, OUTPUT>,
+	{
+		Iter::new(self)
+	}
+
+	/// Returns an endless iterator of random values within a range, which borrows this RNG.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let rolls: Vec<u8> = rng.iter_range(1..=6).take(5).collect();
+	/// assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+	/// ```
+	///
+	/// # Panics
+	/// Iterating will panic if the range doesn't contain any values,
+	/// or if it's a floating point range with bounds that aren't finite.
+	fn iter_range<Number, Bounds>(
+		&mut self,
+		range: Bounds,
+	) -> RangeIter<'_, Number, Bounds, Self, OUTPUT>
+	where
+		Number: RandomRange<BlockRng<Self, OUTPUT>, OUTPUT>,
+		Bounds: RangeBounds<Number> + Clone,
+	{
+		RangeIter::new(self, range)
+	}
+
+	/// Returns an endless iterator of values sampled from a probability [`Distribution`], which borrows this RNG.
+	///
+	/// ```rust
+	/// use nanorand::{distributions::Exponential, Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let waits: Vec<f64> = rng.sample_iter(Exponential::new(0.5).unwrap()).take(5).collect();
+	/// assert!(waits.iter().all(|&wait| wait > 0.0));
+	/// ```
+	fn sample_iter<Sampled, Dist>(
+		&mut self,
+		distribution: Dist,
+	) -> SampleIter<'_, Sampled, Dist, Self, OUTPUT>
+	where
+		Dist: Distribution<Sampled>,
+	{
+		SampleIter::new(self, distribution)
+	}
+
+	/// Generates a [`Vec`] of `length` bytes, each picked uniformly from `alphabet`.
+	///
+	/// # Panics
+	/// This function will panic if `alphabet` is empty.
+	#[cfg(feature = "alloc")]
+	fn bytes_from_alphabet(&mut self, alphabet: &[u8], length: usize) -> Vec<u8> {
+		assert!(!alphabet.is_empty(), "alphabet must not be empty");
+		(0..length)
+			.map(|_| alphabet[self.generate_range(0..alphabet.len())])
+			.collect()
+	}
+
+	/// Generates a [`String`] of `length` characters, each picked uniformly from the characters of `alphabet`.
+	///
+	/// # Panics
+	/// This function will panic if `alphabet` is empty.
+	#[cfg(feature = "alloc")]
+	fn string_from_alphabet(&mut self, alphabet: &str, length: usize) -> String {
+		if alphabet.is_ascii() {
+			let bytes = self.bytes_from_alphabet(alphabet.as_bytes(), length);
+			return String::from_utf8(bytes).expect("alphabet was ASCII");
+		}
+		let alphabet = alphabet.chars().collect::<Vec<char>>();
+		assert!(!alphabet.is_empty(), "alphabet must not be empty");
+		(0..length)
+			.map(|_| alphabet[self.generate_range(0..alphabet.len())])
+			.collect()
+	}
+
+	/// Generates a [`String`] of `length` characters from [`alphabet::ALPHANUMERIC`](crate::alphabet::ALPHANUMERIC).
+	#[cfg(feature = "alloc")]
+	fn alphanumeric(&mut self, length: usize) -> String {
+		self.string_from_alphabet(crate::alphabet::ALPHANUMERIC, length)
+	}
+
+	/// Generates a [`String`] of `length` characters from [`alphabet::HEX`](crate::alphabet::HEX).
+	#[cfg(feature = "alloc")]
+	fn hex(&mut self, length: usize) -> String {
+		self.string_from_alphabet(crate::alphabet::HEX, length)
+	}
+
+	/// Generates a [`String`] of `length` characters from [`alphabet::BASE64_URL`](crate::alphabet::BASE64_URL).
+	#[cfg(feature = "alloc")]
+	fn base64_url(&mut self, length: usize) -> String {
+		self.string_from_alphabet(crate::alphabet::BASE64_URL, length)
+	}
+
 	/// Shuffle a slice, using the RNG.
+	///
+	/// This is a Fisher-Yates shuffle, so every permutation is equally likely,
+	/// but for slices smaller than 2^30 items, it extracts several indices from each random number,
+	/// rather than generating one for every item.
 	fn shuffle<Contents, Array>(&mut self, mut target: Array)
 	where
 		Array: AsMut<[Contents]>,
 	{
-		let target = target.as_mut();
-		let target_len = target.len();
-		for idx in (1..target_len).rev() {
-			let random_idx = self.generate_range(0..idx + 1);
-			target.swap(idx, random_idx);
+		crate::seq::shuffle(self, target.as_mut());
+	}
+
+	/// Shuffles `amount` random items from a slice into its start, and returns them, along with the rest of the slice.
+	/// Unlike [`Rng::shuffle`], this only has to generate `amount` random numbers, rather than one per item.
+	///
+	/// If the slice has fewer than `amount` items, the whole slice is shuffled.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let mut deck: Vec<u8> = (1..=52).collect();
+	/// let (hand, rest) = rng.partial_shuffle(&mut deck, 5);
+	/// assert_eq!((hand.len(), rest.len()), (5, 47));
+	/// ```
+	fn partial_shuffle<'a, Contents>(
+		&mut self,
+		slice: &'a mut [Contents],
+		amount: usize,
+	) -> (&'a mut [Contents], &'a mut [Contents]) {
+		let length = slice.len();
+		let amount = amount.min(length);
+		for position in 0..amount.min(length.saturating_sub(1)) {
+			let swap_with = self.generate_range(position..length);
+			slice.swap(position, swap_with);
+		}
+		slice.split_at_mut(amount)
+	}
+
+	/// Creates an iterator which lazily yields the items of a slice in a random order, without modifying it.
+	/// See [`ShuffledIter`](crate::seq::ShuffledIter) for more details.
+	#[cfg(feature = "alloc")]
+	fn shuffled<'a, Contents>(
+		&'a mut self,
+		slice: &'a [Contents],
+	) -> ShuffledIter<'a, Contents, Self, OUTPUT> {
+		ShuffledIter::new(slice, self)
+	}
+
+	/// Picks a random item from a slice, or returns [`None`] if it's empty.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let colors = ["red", "green", "blue"];
+	/// println!("Your favorite color is {}", rng.choose(&colors).unwrap());
+	/// assert_eq!(rng.choose::<u8>(&[]), None);
+	/// ```
+	fn choose<'a, Contents>(&mut self, slice: &'a [Contents]) -> Option<&'a Contents> {
+		if slice.is_empty() {
+			return None;
+		}
+		slice.get(self.generate_range(0..slice.len()))
+	}
+
+	/// Picks a random item from a slice, and returns a mutable reference to it, or returns [`None`] if it's empty.
+	fn choose_mut<'a, Contents>(&mut self, slice: &'a mut [Contents]) -> Option<&'a mut Contents> {
+		if slice.is_empty() {
+			return None;
+		}
+		let index = self.generate_range(0..slice.len());
+		slice.get_mut(index)
+	}
+
+	/// Picks `amount` distinct items from a slice, in a random order.
+	/// If the slice has fewer than `amount` items, all of them are returned (still in a random order).
+	///
+	/// Small samples are picked with Floyd's algorithm, and larger ones with a partial Fisher-Yates shuffle.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let deck: Vec<u8> = (1..=52).collect();
+	/// let hand = rng.choose_multiple(&deck, 5);
+	/// assert_eq!(hand.len(), 5);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn choose_multiple<'a, Contents>(
+		&mut self,
+		slice: &'a [Contents],
+		amount: usize,
+	) -> Vec<&'a Contents> {
+		let length = slice.len();
+		let amount = amount.min(length);
+		let indices = if amount.saturating_mul(amount) / 2 <= length {
+			// Floyd's algorithm, which only takes O(amount) space. Inserting the duplicates
+			// before the value they collided with (rather than at the end) makes the order uniformly random, too.
+			let mut indices: Vec<usize> = Vec::with_capacity(amount);
+			for upper in length - amount..length {
+				let index = self.generate_range(0..=upper);
+				match indices.iter().position(|&existing| existing == index) {
+					Some(position) => indices.insert(position, upper),
+					None => indices.push(index),
+				}
+			}
+			indices
+		} else {
+			let mut indices: Vec<usize> = (0..length).collect();
+			for position in 0..amount {
+				let swap_with = self.generate_range(position..length);
+				indices.swap(position, swap_with);
+			}
+			indices.truncate(amount);
+			indices
+		};
+		indices.into_iter().map(|index| &slice[index]).collect()
+	}
+
+	/// Picks a random item from an iterator, or returns [`None`] if it's empty.
+	///
+	/// If the iterator knows exactly how long it is, this skips straight to the chosen item.
+	/// Otherwise, it uses reservoir sampling, so the iterator only has to be walked through once.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let word = rng.choose_iter("the quick brown fox".split(' ')).unwrap();
+	/// println!("{}", word);
+	/// ```
+	fn choose_iter<Iter: IntoIterator>(&mut self, iter: Iter) -> Option<Iter::Item> {
+		let mut iter = iter.into_iter();
+		if let (lower, Some(upper)) = iter.size_hint() {
+			if lower == upper {
+				if lower == 0 {
+					return None;
+				}
+				return iter.nth(self.generate_range(0..lower));
+			}
+		}
+		let mut chosen = iter.next()?;
+		// Algorithm L, with a reservoir of one.
+		let mut w = self.generate::<OpenClosed01<f64>>().0;
+		loop {
+			let skip =
+				math::floor(math::ln(self.generate::<OpenClosed01<f64>>().0) / math::ln_1p(-w));
+			// This cast saturates, which is what we want.
+			match iter.nth(skip as usize) {
+				Some(item) => chosen = item,
+				None => return Some(chosen),
+			}
+			w *= self.generate::<OpenClosed01<f64>>().0;
+		}
+	}
+
+	/// Picks `amount` distinct items from an iterator, in no particular order,
+	/// using Li's "Algorithm L" for reservoir sampling.
+	/// If the iterator has fewer than `amount` items, all of them are returned.
+	///
+	/// This only walks through the iterator once, without needing to know how long it is,
+	/// and skips over most items without generating a random number for each of them.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new();
+	/// let lines = "a\nb\nc\nd\ne\nf".lines();
+	/// assert_eq!(rng.choose_multiple_iter(lines, 3).len(), 3);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn choose_multiple_iter<Iter: IntoIterator>(
+		&mut self,
+		iter: Iter,
+		amount: usize,
+	) -> Vec<Iter::Item> {
+		let mut iter = iter.into_iter();
+		let mut reservoir: Vec<Iter::Item> = iter.by_ref().take(amount).collect();
+		if reservoir.len() < amount || amount == 0 {
+			return reservoir;
+		}
+		let inverse_amount = 1.0 / amount as f64;
+		let mut w = math::powf(self.generate::<OpenClosed01<f64>>().0, inverse_amount);
+		loop {
+			let skip =
+				math::floor(math::ln(self.generate::<OpenClosed01<f64>>().0) / math::ln_1p(-w));
+			match iter.nth(skip as usize) {
+				Some(item) => reservoir[self.generate_range(0..amount)] = item,
+				None => return reservoir,
+			}
+			w *= math::powf(self.generate::<OpenClosed01<f64>>().0, inverse_amount);
 		}
 	}
 }
 
-/// A trait that represents an RNG that can be reseeded from arbitrary bytes.
+/// A trait that represents an RNG that can be created and reseeded from arbitrary bytes.
 pub trait SeedableRng<const SEED_SIZE: usize, const OUTPUT: usize>: Rng<OUTPUT> {
+	/// Create a new instance of the RNG from the specified bytes.
+	fn from_seed(seed: [u8; SEED_SIZE]) -> Self;
+
 	/// Re-seed the RNG with the specified bytes.
-	fn reseed(&mut self, seed: [u8; SEED_SIZE]);
+	fn reseed(&mut self, seed: [u8; SEED_SIZE]) {
+		*self = Self::from_seed(seed);
+	}
+
+	/// Create a new instance of the RNG from a single `u64`, by expanding it into a full seed with [`SplitMix64`].
+	///
+	/// Nearby seeds such as 0, 1 and 2 give unrelated generators, and every generator is seeded the same way,
+	/// which makes this the simplest way to get reproducible output from generic code.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, SeedableRng, WyRand};
+	///
+	/// let mut a = WyRand::seed_from_u64(42);
+	/// let mut b = WyRand::seed_from_u64(42);
+	/// assert_eq!(a.generate::<u64>(), b.generate::<u64>());
+	/// ```
+	fn seed_from_u64(seed: u64) -> Self {
+		let mut bytes = [0_u8; SEED_SIZE];
+		SplitMix64::new_seed(seed).fill_bytes(&mut bytes);
+		Self::from_seed(bytes)
+	}
+
+	/// Create a new instance of the RNG, seeding from the system's default source of entropy.
+	///
+	/// This is the same as calling [`SeedableRng::from_seed`] with random bytes,
+	/// so RNGs with more state than their seed, such as a PCG stream, keep their defaults.
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	fn from_entropy() -> Self {
+		let mut seed = [0_u8; SEED_SIZE];
+		crate::entropy::system(&mut seed);
+		Self::from_seed(seed)
+	}
+
+	/// Create a new instance of the RNG, seeded with bytes from another RNG.
+	///
+	/// ```rust
+	/// use nanorand::{ChaCha20, Rng, SeedableRng, WyRand};
+	///
+	/// let mut master = ChaCha20::seed_from_u64(42);
+	/// let mut a = WyRand::from_rng(&mut master);
+	/// let mut b = WyRand::from_rng(&mut master);
+	/// assert_ne!(a.generate::<u64>(), b.generate::<u64>());
+	/// ```
+	fn from_rng<Source: Rng<SOURCE_OUTPUT>, const SOURCE_OUTPUT: usize>(rng: &mut Source) -> Self {
+		let mut seed = [0_u8; SEED_SIZE];
+		rng.fill_bytes(&mut seed);
+		Self::from_seed(seed)
+	}
+}
+
+#[cfg(all(test, feature = "wyrand"))]
+mod tests {
+	use super::{Rng, SeedableRng, SplitMix64, WyRand};
+
+	/// Checks that each of the counts is within 5 standard deviations of `expected`.
+	fn check_uniform(counts: &[usize], expected: f64, probability: f64) {
+		let tolerance = 5.0 * (expected * (1.0 - probability)).sqrt();
+		for (index, &count) in counts.iter().enumerate() {
+			assert!(
+				(count as f64 - expected).abs() <= tolerance,
+				"{} was picked {} times, expected {} ({:?})",
+				index,
+				count,
+				expected,
+				counts
+			);
+		}
+	}
+
+	#[test]
+	fn ensure_choose_is_uniform() {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let items: Vec<usize> = (0..10).collect();
+		let mut counts = [0; 10];
+		let mut iter_counts = [0; 10];
+		let mut exact_iter_counts = [0; 10];
+		for _ in 0..100_000 {
+			counts[*rng.choose(&items).unwrap()] += 1;
+			// Filtering hides the length of the iterator, so this goes through reservoir sampling.
+			iter_counts[rng
+				.choose_iter(items.iter().filter(|_| true))
+				.copied()
+				.unwrap()] += 1;
+			exact_iter_counts[rng.choose_iter(items.iter()).copied().unwrap()] += 1;
+		}
+		check_uniform(&counts, 10_000.0, 0.1);
+		check_uniform(&iter_counts, 10_000.0, 0.1);
+		check_uniform(&exact_iter_counts, 10_000.0, 0.1);
+
+		assert_eq!(rng.choose::<u8>(&[]), None);
+		assert_eq!(rng.choose_iter(core::iter::empty::<u8>()), None);
+		assert_eq!(rng.choose_iter((0..10).filter(|_| false)), None);
+
+		let mut items = [0_u8; 4];
+		*rng.choose_mut(&mut items).unwrap() = 1;
+		assert_eq!(items.iter().sum::<u8>(), 1);
+		assert_eq!(rng.choose_mut::<u8>(&mut []), None);
+	}
+
+	#[test]
+	fn ensure_choose_multiple_is_uniform() {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let items: Vec<usize> = (0..20).collect();
+		// The first amount uses Floyd's algorithm, and the second a partial shuffle.
+		for amount in [3, 15] {
+			let mut included = [0; 20];
+			let mut first = [0; 20];
+			let mut reservoir = [0; 20];
+			for _ in 0..50_000 {
+				let chosen = rng.choose_multiple(&items, amount);
+				assert_eq!(chosen.len(), amount);
+				let mut sorted = chosen.clone();
+				sorted.sort_unstable();
+				sorted.dedup();
+				assert_eq!(sorted.len(), amount, "{:?} had duplicates", chosen);
+				for &&item in &chosen {
+					included[item] += 1;
+				}
+				// The order should be random too, so each item should be first equally often.
+				first[*chosen[0]] += 1;
+
+				let chosen = rng.choose_multiple_iter(items.iter().copied(), amount);
+				assert_eq!(chosen.len(), amount);
+				for item in chosen {
+					reservoir[item] += 1;
+				}
+			}
+			let probability = amount as f64 / 20.0;
+			check_uniform(&included, 50_000.0 * probability, probability);
+			check_uniform(&reservoir, 50_000.0 * probability, probability);
+			check_uniform(&first, 2_500.0, 0.05);
+		}
+
+		// Long streams are where Algorithm L skips the most, so check those by bucketing the items.
+		let (mut single, mut multiple) = ([0; 10], [0; 10]);
+		for _ in 0..20_000 {
+			single[rng.choose_iter((0..10_000).filter(|_| true)).unwrap() / 1000] += 1;
+			for item in rng.choose_multiple_iter(0..10_000, 5) {
+				multiple[item / 1000] += 1;
+			}
+		}
+		check_uniform(&single, 2_000.0, 0.1);
+		check_uniform(&multiple, 10_000.0, 0.1);
+
+		assert_eq!(rng.choose_multiple(&items, 100).len(), 20);
+		assert_eq!(rng.choose_multiple(&items, 0).len(), 0);
+		assert_eq!(rng.choose_multiple_iter(0..5, 10), vec![0, 1, 2, 3, 4]);
+		assert!(rng.choose_multiple_iter(0..5, 0).is_empty());
+	}
+
+	#[test]
+	fn ensure_choosing_is_deterministic() {
+		let items: Vec<u32> = (0..1000).collect();
+		let choose = |seed| {
+			let mut rng = WyRand::new_seed(seed);
+			(
+				rng.choose(&items).copied(),
+				rng.choose_multiple(&items, 10),
+				rng.choose_iter(items.iter().filter(|x| *x % 3 == 0)),
+				rng.choose_multiple_iter(items.iter(), 10),
+			)
+		};
+		assert_eq!(choose(42), choose(42));
+		assert_ne!(choose(42), choose(43));
+	}
+
+	#[test]
+	fn ensure_seed_from_u64_expands_with_splitmix64() {
+		let mut expanded = [0_u8; 8];
+		SplitMix64::new_seed(42).fill_bytes(&mut expanded);
+		assert_eq!(
+			WyRand::seed_from_u64(42).rand(),
+			WyRand::from_seed(expanded).rand()
+		);
+		assert_ne!(
+			WyRand::seed_from_u64(0).rand(),
+			WyRand::seed_from_u64(1).rand()
+		);
+	}
+
+	#[test]
+	fn ensure_from_rng_uses_source_output() {
+		let mut source = SplitMix64::new_seed(42);
+		let mut expected = [0_u8; 8];
+		source.clone().fill_bytes(&mut expected);
+		assert_eq!(
+			WyRand::from_rng(&mut source).rand(),
+			WyRand::from_seed(expected).rand()
+		);
+		// The source moves on, so the next generator is different.
+		assert_ne!(
+			WyRand::from_rng(&mut source).rand(),
+			WyRand::from_seed(expected).rand()
+		);
+	}
+
+	#[test]
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	fn ensure_from_entropy_is_random() {
+		assert_ne!(WyRand::from_entropy().rand(), WyRand::from_entropy().rand());
+	}
 }
@@ -22,6 +22,10 @@ pub type ChaCha20 = ChaCha<20>;
 #[cfg_attr(feature = "zeroize", zeroize(drop))]
 pub struct ChaCha<const ROUNDS: u8> {
 	state: [u32; 16],
+	/// Whether the state is in the RFC 8439 layout, with a 32-bit counter and a 96-bit nonce.
+	ietf: bool,
+	/// How many words of the block at the current counter have already been used.
+	offset: u8,
 }
 
 impl<const ROUNDS: u8> ChaCha<ROUNDS> {
@@ -35,14 +39,148 @@ impl<const ROUNDS: u8> ChaCha<ROUNDS> {
 		let mut nonce: [u8; 8] = Default::default();
 		crate::entropy::system(&mut nonce);
 		let state = chacha::chacha_init(key, counter, nonce);
-		Self { state }
+		Self {
+			state,
+			ietf: false,
+			offset: 0,
+		}
 	}
 
 	/// Create a new [`ChaCha`] instance, using the provided key and nonce.
 	#[must_use]
 	pub const fn new_key(key: [u8; 32], counter: [u8; 8], nonce: [u8; 8]) -> Self {
 		let state = chacha::chacha_init(key, counter, nonce);
-		Self { state }
+		Self {
+			state,
+			ietf: false,
+			offset: 0,
+		}
+	}
+
+	/// Create a new [`ChaCha`] instance in the RFC 8439 (IETF) layout, using the provided key, 32-bit counter and 96-bit nonce.
+	///
+	/// The counter only has room for 2^32 blocks, or 256 GiB of output, before the generator has to reseed itself.
+	#[must_use]
+	pub const fn new_ietf(key: [u8; 32], counter: [u8; 4], nonce: [u8; 12]) -> Self {
+		let state = chacha::chacha_init_ietf(key, counter, nonce);
+		Self {
+			state,
+			ietf: true,
+			offset: 0,
+		}
+	}
+
+	/// Create a new XChaCha instance, using the provided key and 192-bit nonce.
+	///
+	/// The nonce is large enough to be picked at random, or taken from protocol material, without worrying about reuse.
+	/// The subkey is derived with HChaCha using the same number of rounds, as in draft-irtf-cfrg-xchacha.
+	#[must_use]
+	pub fn new_xchacha(key: [u8; 32], counter: [u8; 8], nonce: [u8; 24]) -> Self {
+		let state = chacha::xchacha_init::<ROUNDS>(key, counter, nonce);
+		Self {
+			state,
+			ietf: false,
+			offset: 0,
+		}
+	}
+
+	/// The bits of the block counter that [`ChaCha::set_word_pos`] can change.
+	const fn counter_mask(&self) -> u64 {
+		if self.ietf {
+			u32::MAX as u64
+		} else {
+			u64::MAX
+		}
+	}
+
+	const fn counter(&self) -> u64 {
+		((self.state[13] as u64) << 32) | (self.state[12] as u64)
+	}
+
+	fn set_counter(&mut self, counter: u64) {
+		self.state[12] = counter as u32;
+		if !self.ietf {
+			self.state[13] = (counter >> 32) as u32;
+		}
+	}
+
+	/// Returns the position of the next 32-bit word of the keystream that [`Rng::rand`] will return.
+	///
+	/// ```rust
+	/// use nanorand::{ChaCha20, Rng};
+	///
+	/// let mut rng = ChaCha20::new_key([42; 32], [0; 8], [0; 8]);
+	/// rng.rand();
+	/// assert_eq!(rng.get_word_pos(), 16);
+	/// ```
+	#[must_use]
+	pub const fn get_word_pos(&self) -> u128 {
+		((self.counter() & self.counter_mask()) as u128) * 16 + self.offset as u128
+	}
+
+	/// Moves the generator to a 32-bit word position in its keystream, so the next call to [`Rng::rand`]
+	/// starts from that word. This gives random access to the whole stream.
+	///
+	/// The block counter wraps around, so only the low 68 bits of `word_pos` are used,
+	/// or the low 36 bits for a generator in the IETF layout.
+	///
+	/// ```rust
+	/// use nanorand::{ChaCha20, Rng};
+	///
+	/// let mut rng = ChaCha20::new_key([42; 32], [0; 8], [0; 8]);
+	/// let mut keystream = [0; 128];
+	/// rng.fill_bytes(&mut keystream);
+	/// rng.set_word_pos(5);
+	/// assert_eq!(rng.rand()[..], keystream[20..84]);
+	/// ```
+	pub fn set_word_pos(&mut self, word_pos: u128) {
+		self.set_counter((word_pos >> 4) as u64 & self.counter_mask());
+		self.offset = (word_pos & 15) as u8;
+	}
+
+	/// Moves the generator forwards, or backwards if `words` is negative, by a number of 32-bit words in its keystream,
+	/// wrapping around at either end.
+	pub fn seek(&mut self, words: i128) {
+		self.set_word_pos(self.get_word_pos().wrapping_add(words as u128));
+	}
+
+	/// Returns the stream, which is the last 64 bits of the nonce.
+	#[must_use]
+	pub const fn get_stream(&self) -> u64 {
+		((self.state[15] as u64) << 32) | (self.state[14] as u64)
+	}
+
+	/// Switches to another stream with the same key, keeping the word position, by replacing the last 64 bits of the nonce.
+	///
+	/// In the original layout this is the whole nonce, so each key has 2^64 independent streams.
+	/// In the IETF layout, the first 32 bits of the nonce are left as they are.
+	pub fn set_stream(&mut self, stream: u64) {
+		self.state[14] = stream as u32;
+		self.state[15] = (stream >> 32) as u32;
+	}
+
+	/// Moves on to the next block, reseeding entirely if the counter overflows.
+	fn increment_counter(&mut self) {
+		let incremented = if self.ietf {
+			chacha::chacha_increment_counter_ietf(&mut self.state)
+		} else {
+			chacha::chacha_increment_counter(&mut self.state)
+		};
+		if incremented {
+			return;
+		}
+		if self.ietf {
+			// Re-key in the same layout, so the counter keeps its width.
+			let mut key = [42_u8; 32];
+			let mut nonce = [42_u8; 12];
+			crate::entropy::system(&mut key);
+			crate::entropy::system(&mut nonce);
+			self.state = chacha::chacha_init_ietf(key, [0; 4], nonce);
+		} else {
+			let mut new_seed: [u8; 40] = [42_u8; 40];
+			crate::entropy::system(&mut new_seed);
+			self.reseed(new_seed);
+		}
 	}
 }
 
@@ -55,36 +193,77 @@ impl<const ROUNDS: u8> Default for ChaCha<ROUNDS> {
 		let mut nonce: [u8; 8] = Default::default();
 		crate::entropy::system(&mut nonce);
 		let state = chacha::chacha_init(key, counter, nonce);
-		Self { state }
+		Self {
+			state,
+			ietf: false,
+			offset: 0,
+		}
 	}
 }
 
 impl<const ROUNDS: u8> Rng<64> for ChaCha<ROUNDS> {
 	fn rand(&mut self) -> [u8; 64] {
+		let offset = self.offset as usize;
 		let block = chacha::chacha_block::<ROUNDS>(self.state);
 		let mut ret = [0_u8; 64];
-		block.iter().enumerate().for_each(|(idx, num)| {
-			let x = num.to_ne_bytes();
-			let n = idx * 4;
-			ret[n] = x[0];
-			ret[n + 1] = x[1];
-			ret[n + 2] = x[2];
-			ret[n + 3] = x[3];
-		});
+		ret.chunks_exact_mut(4)
+			.zip(block[offset..].iter())
+			.for_each(|(bytes, word)| bytes.copy_from_slice(&word.to_le_bytes()));
 		// Now, we're going to just increment our counter so we get an entirely new output next time.
 		// If the counter overflows, we just reseed entirely instead.
-		if !chacha::chacha_increment_counter(&mut self.state) {
-			let mut new_seed: [u8; 40] = [42_u8; 40];
-			crate::entropy::system(&mut new_seed);
-			self.reseed(new_seed);
+		self.increment_counter();
+		if offset > 0 {
+			// Partway through a block, the rest of the output comes from the start of the next one.
+			let block = chacha::chacha_block::<ROUNDS>(self.state);
+			ret[(16 - offset) * 4..]
+				.chunks_exact_mut(4)
+				.zip(block[..offset].iter())
+				.for_each(|(bytes, word)| bytes.copy_from_slice(&word.to_le_bytes()));
+			self.offset = offset as u8;
 		}
 		ret
 	}
+
+	/// Fills whole blocks of `buffer` several at a time, using SIMD where it's available,
+	/// and gives exactly the same bytes as calling [`Rng::rand`] over and over.
+	fn fill_bytes<Bytes>(&mut self, mut buffer: Bytes)
+	where
+		Bytes: AsMut<[u8]>,
+	{
+		let buffer = buffer.as_mut();
+		if self.offset > 0 {
+			// The blocks don't line up with the output, so there's nothing to batch.
+			for chunk in buffer.chunks_mut(64) {
+				let block = self.rand();
+				chunk.copy_from_slice(&block[..chunk.len()]);
+			}
+			return;
+		}
+		// Stop short of the last block before the counter overflows, so that `rand` can reseed there.
+		let remaining = if self.ietf {
+			(u32::MAX - self.state[12]) as u64
+		} else {
+			u64::MAX - (((self.state[13] as u64) << 32) | (self.state[12] as u64))
+		};
+		let blocks = ((buffer.len() / 64) as u64).min(remaining) as usize;
+		let (whole, rest) = buffer.split_at_mut(blocks * 64);
+		chacha::chacha_blocks::<ROUNDS>(self.state, whole);
+		chacha::chacha_add_to_counter(&mut self.state, blocks as u64);
+
+		for chunk in rest.chunks_mut(64) {
+			let block = self.rand();
+			chunk.copy_from_slice(&block[..chunk.len()]);
+		}
+	}
 }
 
 impl<const ROUNDS: u8> Clone for ChaCha<ROUNDS> {
 	fn clone(&self) -> Self {
-		Self { state: self.state }
+		Self {
+			state: self.state,
+			ietf: self.ietf,
+			offset: self.offset,
+		}
 	}
 }
 
@@ -95,22 +274,193 @@ impl<const ROUNDS: u8> Display for ChaCha<ROUNDS> {
 }
 
 impl<const ROUNDS: u8> SeedableRng<40, 64> for ChaCha<ROUNDS> {
-	fn reseed(&mut self, seed: [u8; 40]) {
+	fn from_seed(seed: [u8; 40]) -> Self {
 		let mut key = [0_u8; 32];
 		let mut nonce = [0_u8; 8];
 		key.copy_from_slice(&seed[..32]);
 		let counter = [0u8; 8];
 		nonce.copy_from_slice(&seed[32..]);
-		self.state = chacha::chacha_init(key, counter, nonce);
+		Self::new_key(key, counter, nonce)
 	}
 }
 
 impl<const ROUNDS: u8> Debug for ChaCha<ROUNDS> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-		let counter = ((self.state[13] as u64) << 32) | (self.state[12] as u64);
 		f.debug_struct("ChaCha20")
 			.field("rounds", &ROUNDS)
-			.field("counter", &counter)
+			.field("word_pos", &self.get_word_pos())
+			.field("stream", &self.get_stream())
 			.finish()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ensure_output_matches_keystream() {
+		// The first block of the last keystream in the crypto module's test vectors, byte for byte.
+		let mut key = [0_u8; 32];
+		for (index, byte) in key.iter_mut().enumerate() {
+			*byte = index as u8;
+		}
+		let mut rng = ChaCha20::new_key(key, [0; 8], [0, 1, 2, 3, 4, 5, 6, 7]);
+		let block = rng.rand();
+		assert_eq!(
+			block[..],
+			[
+				0xf7, 0x98, 0xa1, 0x89, 0xf1, 0x95, 0xe6, 0x69, 0x82, 0x10, 0x5f, 0xfb, 0x64, 0x0b,
+				0xb7, 0x75, 0x7f, 0x57, 0x9d, 0xa3, 0x16, 0x02, 0xfc, 0x93, 0xec, 0x01, 0xac, 0x56,
+				0xf8, 0x5a, 0xc3, 0xc1, 0x34, 0xa4, 0x54, 0x7b, 0x73, 0x3b, 0x46, 0x41, 0x30, 0x42,
+				0xc9, 0x44, 0x00, 0x49, 0x17, 0x69, 0x05, 0xd3, 0xbe, 0x59, 0xea, 0x1c, 0x53, 0xf1,
+				0x59, 0x16, 0x15, 0x5c, 0x2b, 0xe8, 0x24, 0x1a,
+			][..]
+		);
+	}
+
+	#[test]
+	fn ensure_ietf_output_matches_rfc8439() {
+		// RFC 8439, section 2.3.2
+		let mut key = [0_u8; 32];
+		for (index, byte) in key.iter_mut().enumerate() {
+			*byte = index as u8;
+		}
+		let nonce = [0, 0, 0, 9, 0, 0, 0, 0x4a, 0, 0, 0, 0];
+		let mut rng = ChaCha20::new_ietf(key, [1, 0, 0, 0], nonce);
+		assert_eq!(
+			rng.rand()[..],
+			[
+				0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20,
+				0x71, 0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a,
+				0xc3, 0xd4, 0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2,
+				0xd7, 0x05, 0xd9, 0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9,
+				0xcb, 0xd0, 0x83, 0xe8, 0xa2, 0x50, 0x3c, 0x4e,
+			][..]
+		);
+		// Only the 32-bit counter moves on, leaving the whole nonce alone.
+		assert_eq!(
+			rng.rand(),
+			ChaCha20::new_ietf(key, [2, 0, 0, 0], nonce).rand()
+		);
+	}
+
+	#[test]
+	fn ensure_xchacha_output_matches_draft() {
+		// draft-irtf-cfrg-xchacha, appendix A.3.2
+		let mut key = [0_u8; 32];
+		for (index, byte) in key.iter_mut().enumerate() {
+			*byte = 0x80 + index as u8;
+		}
+		let mut nonce = [0_u8; 24];
+		for (index, byte) in nonce.iter_mut().enumerate() {
+			*byte = 0x40 + index as u8;
+		}
+		nonce[23] = 0x58;
+		let mut rng = ChaCha20::new_xchacha(key, [1, 0, 0, 0, 0, 0, 0, 0], nonce);
+		assert_eq!(
+			rng.rand()[..8],
+			[0x29, 0x62, 0x4b, 0x4b, 0x1b, 0x14, 0x0a, 0xce]
+		);
+	}
+
+	fn check_fill_bytes(rng: &ChaCha20, length: usize) {
+		let mut filled = vec![0; length];
+		let mut fill_rng = rng.clone();
+		fill_rng.fill_bytes(&mut filled);
+
+		let mut expected = Vec::with_capacity(length + 64);
+		let mut rand_rng = rng.clone();
+		while expected.len() < length {
+			expected.extend_from_slice(&rand_rng.rand());
+		}
+		expected.truncate(length);
+		assert_eq!(filled, expected);
+	}
+
+	#[test]
+	fn ensure_fill_bytes_matches_rand() {
+		let generators = [
+			ChaCha20::new_key([7; 32], [0; 8], [3; 8]),
+			ChaCha20::new_ietf([7; 32], u32::to_le_bytes(u32::MAX - 100), [3; 12]),
+		];
+		for rng in &generators {
+			for length in [0, 1, 63, 64, 100, 640, 1000, 4103] {
+				check_fill_bytes(rng, length);
+			}
+		}
+
+		// Batching has to stop before the last block of a 32-bit counter, which is left to `rand`.
+		// Anything after that is reseeded from entropy, so it can't be compared.
+		let rng = ChaCha20::new_ietf([7; 32], u32::to_le_bytes(u32::MAX - 10), [3; 12]);
+		check_fill_bytes(&rng, 11 * 64);
+	}
+
+	#[test]
+	fn ensure_ietf_layout_survives_counter_overflow() {
+		let mut rng = ChaCha20::new_ietf([7; 32], u32::to_le_bytes(u32::MAX), [3; 12]);
+		rng.rand();
+		assert!(rng.ietf);
+		assert_eq!(rng.get_word_pos(), 0);
+		rng.rand();
+		assert!(rng.ietf);
+		assert_eq!(rng.get_word_pos(), 16);
+
+		let mut rng = ChaCha20::new_ietf([7; 32], u32::to_le_bytes(u32::MAX - 1), [3; 12]);
+		rng.fill_bytes([0; 64 * 5]);
+		assert!(rng.ietf);
+		assert_eq!(rng.get_word_pos(), 3 * 16);
+	}
+
+	#[test]
+	fn ensure_word_pos_gives_random_access() {
+		let rng = ChaCha20::new_key([7; 32], [0; 8], [3; 8]);
+		let mut keystream = vec![0; 64 * 8];
+		rng.clone().fill_bytes(&mut keystream);
+
+		for word_pos in [0, 1, 15, 16, 17, 40, 95] {
+			let mut seeked = rng.clone();
+			seeked.set_word_pos(word_pos);
+			assert_eq!(seeked.get_word_pos(), word_pos);
+			let start = word_pos as usize * 4;
+			assert_eq!(seeked.rand()[..], keystream[start..start + 64]);
+			assert_eq!(seeked.rand()[..], keystream[start + 64..start + 128]);
+			assert_eq!(seeked.get_word_pos(), word_pos + 32);
+
+			// Filling from partway through a block has to give the same stream too.
+			seeked.seek(-32);
+			let mut filled = [0; 130];
+			seeked.fill_bytes(&mut filled);
+			assert_eq!(filled[..], keystream[start..start + 130]);
+		}
+	}
+
+	#[test]
+	fn ensure_word_pos_wraps_around() {
+		let mut rng = ChaCha20::new_key([7; 32], [0; 8], [3; 8]);
+		rng.seek(-1);
+		assert_eq!(rng.get_word_pos(), (1 << 68) - 1);
+		rng.seek(1);
+		assert_eq!(rng.get_word_pos(), 0);
+
+		// The IETF layout has a 32-bit counter, which mustn't spill into the nonce.
+		let mut rng = ChaCha20::new_ietf([7; 32], [0; 4], [3; 12]);
+		rng.set_word_pos(1 << 36 | 5);
+		assert_eq!(rng.get_word_pos(), 5);
+		assert_eq!(rng.state[13..], [0x03030303; 3]);
+	}
+
+	#[test]
+	fn ensure_stream_can_be_switched() {
+		let mut rng = ChaCha20::new_key([7; 32], [0; 8], [3; 8]);
+		assert_eq!(rng.get_stream(), 0x0303030303030303);
+		rng.set_word_pos(21);
+		rng.set_stream(42);
+		assert_eq!(rng.get_stream(), 42);
+		assert_eq!(rng.get_word_pos(), 21);
+
+		let mut expected = ChaCha20::new_key([7; 32], [0; 8], u64::to_le_bytes(42));
+		expected.set_word_pos(21);
+		assert_eq!(rng.rand(), expected.rand());
+	}
+}
@@ -0,0 +1,210 @@
+// Based off the PCG reference implementation at https://github.com/imneme/pcg-c
+
+use crate::rand::{Rng, SeedableRng};
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const PCG_DEFAULT_MULTIPLIER_64: u64 = 6364136223846793005;
+const PCG_DEFAULT_INCREMENT_64: u64 = 1442695040888963407;
+
+/// Steps the LCG `state * multiplier + increment` forward `delta` times, in `O(log delta)` time.
+/// This is the 64-bit version of [`lcg_advance`](super::pcg64::lcg_advance).
+const fn lcg_advance(state: u64, mut multiplier: u64, mut increment: u64, mut delta: u64) -> u64 {
+	let mut total_multiplier = 1_u64;
+	let mut total_increment = 0_u64;
+	while delta > 0 {
+		if delta & 1 == 1 {
+			total_multiplier = total_multiplier.wrapping_mul(multiplier);
+			total_increment = total_increment
+				.wrapping_mul(multiplier)
+				.wrapping_add(increment);
+		}
+		increment = multiplier.wrapping_add(1).wrapping_mul(increment);
+		multiplier = multiplier.wrapping_mul(multiplier);
+		delta >>= 1;
+	}
+	total_multiplier
+		.wrapping_mul(state)
+		.wrapping_add(total_increment)
+}
+
+/// An instance of the Pcg32 random number generator, which is PCG XSH RR 64/32:
+/// a 64-bit LCG with one of 2^63 selectable streams, and a 32-bit output.
+///
+/// This matches `pcg32` from the PCG reference implementations, and `Pcg32` from `rand_pcg`.
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+///
+/// ```rust
+/// use nanorand::{Pcg32, Rng};
+///
+/// let mut rng = Pcg32::new_stream(42, 54);
+/// assert_eq!(rng.generate::<u32>(), 0xa15c02b7);
+/// ```
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[cfg_attr(feature = "zeroize", zeroize(drop))]
+pub struct Pcg32 {
+	state: u64,
+	increment: u64,
+}
+
+impl Pcg32 {
+	/// Create a new [`Pcg32`] instance, with a random seed and stream from the system's default source of entropy.
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a new [`Pcg32`] instance, using a provided seed and the default stream.
+	#[must_use]
+	pub const fn new_seed(seed: u64) -> Self {
+		Self::from_state_increment(seed, PCG_DEFAULT_INCREMENT_64)
+	}
+
+	/// Create a new [`Pcg32`] instance, using a provided seed and stream.
+	/// Only the low 63 bits of `stream` are used.
+	#[must_use]
+	pub const fn new_stream(seed: u64, stream: u64) -> Self {
+		Self::from_state_increment(seed, (stream << 1) | 1)
+	}
+
+	const fn from_state_increment(seed: u64, increment: u64) -> Self {
+		// This is the reference seeding procedure: step from zero, add the seed, and step again.
+		let state = increment
+			.wrapping_add(seed)
+			.wrapping_mul(PCG_DEFAULT_MULTIPLIER_64)
+			.wrapping_add(increment);
+		Self { state, increment }
+	}
+
+	/// Skips ahead `delta` outputs, as if [`Rng::rand`] had been called `delta` times, in `O(log delta)` time.
+	///
+	/// [`Pcg32`] repeats itself after 2^64 outputs, so only the low 64 bits of `delta` matter.
+	pub fn advance(&mut self, delta: u128) {
+		self.state = lcg_advance(
+			self.state,
+			PCG_DEFAULT_MULTIPLIER_64,
+			self.increment,
+			delta as u64,
+		);
+	}
+
+	/// Skips ahead 2^32 outputs.
+	///
+	/// This can be used to hand out up to 2^32 non-overlapping pieces of the same stream;
+	/// if you don't need them to share a stream, [`Pcg32::new_stream`] is simpler.
+	pub fn jump(&mut self) {
+		self.advance(1 << 32);
+	}
+
+	/// Skips ahead 2^48 outputs.
+	///
+	/// This can be used to hand out up to 2^16 starting points, each of which can be split further with [`Pcg32::jump`].
+	pub fn long_jump(&mut self) {
+		self.advance(1 << 48);
+	}
+}
+
+#[cfg(any(feature = "entropy", feature = "getrandom"))]
+impl Default for Pcg32 {
+	/// Create a new [`Pcg32`] instance, with a random seed and stream from the system's default source of entropy.
+	fn default() -> Self {
+		let mut entropy: [u8; core::mem::size_of::<u64>() * 2] = Default::default();
+		crate::entropy::system(&mut entropy);
+		let mut seed = [0_u8; 8];
+		let mut stream = [0_u8; 8];
+		seed.copy_from_slice(&entropy[..8]);
+		stream.copy_from_slice(&entropy[8..]);
+		Self::new_stream(u64::from_le_bytes(seed), u64::from_le_bytes(stream))
+	}
+}
+
+impl Rng<4> for Pcg32 {
+	fn rand(&mut self) -> [u8; 4] {
+		let state = self.state;
+		self.state = state
+			.wrapping_mul(PCG_DEFAULT_MULTIPLIER_64)
+			.wrapping_add(self.increment);
+		// XSH RR: xorshift the high bits down, then rotate by the top 5 bits.
+		let rotation = (state >> 59) as u32;
+		let output = (((state >> 18) ^ state) >> 27) as u32;
+		output.rotate_right(rotation).to_le_bytes()
+	}
+}
+
+impl SeedableRng<8, 4> for Pcg32 {
+	/// Create a new [`Pcg32`] instance from a seed, using the default stream.
+	fn from_seed(seed: [u8; 8]) -> Self {
+		Self::new_seed(u64::from_le_bytes(seed))
+	}
+
+	/// Restarts the current stream from a new seed.
+	fn reseed(&mut self, seed: [u8; 8]) {
+		*self = Self::from_state_increment(u64::from_le_bytes(seed), self.increment);
+	}
+}
+
+impl Clone for Pcg32 {
+	fn clone(&self) -> Self {
+		Self {
+			state: self.state,
+			increment: self.increment,
+		}
+	}
+}
+
+impl Display for Pcg32 {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "Pcg32 ({:p})", self)
+	}
+}
+
+impl Debug for Pcg32 {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Pcg32")
+			.field("state", &format_args!("0x{:x}", self.state))
+			.field("increment", &format_args!("0x{:x}", self.increment))
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ensure_pcg32_matches_reference() {
+		// From pcg-c's check-pcg32 test, with seed 42 and stream 54.
+		let mut rng = Pcg32::new_stream(42, 54);
+		let mut outputs = [0_u32; 6];
+		for output in &mut outputs {
+			*output = u32::from_le_bytes(rng.rand());
+		}
+		assert_eq!(
+			outputs,
+			[0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]
+		);
+	}
+
+	#[test]
+	fn ensure_advance_matches_stepping() {
+		for delta in [0, 1, 2, 7, 1_000] {
+			let mut stepped = Pcg32::new_stream(0x5eed, 54);
+			let mut advanced = stepped.clone();
+			for _ in 0..delta {
+				stepped.rand();
+			}
+			advanced.advance(delta);
+			assert_eq!(stepped.rand(), advanced.rand());
+		}
+
+		let mut jumped = Pcg32::new_seed(0x5eed);
+		let mut advanced = jumped.clone();
+		jumped.jump();
+		jumped.long_jump();
+		advanced.advance((1 << 48) + (1 << 32) + (1 << 64));
+		assert_eq!(jumped.rand(), advanced.rand());
+	}
+}
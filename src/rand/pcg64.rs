@@ -1,99 +1,188 @@
-// Based off Robert Kern's C implementation at https://github.com/rkern/pcg64/blob/master/pcg64.c
+// Based off the PCG reference implementations at https://github.com/imneme/pcg-c and https://github.com/imneme/pcg-cpp
 
 use crate::rand::{Rng, SeedableRng};
 use core::fmt::{self, Debug, Display, Formatter};
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
-const PCG_DEFAULT_MULTIPLIER_128: u128 = 47026247687942121848144207491837523525;
+pub(crate) const PCG_DEFAULT_MULTIPLIER_128: u128 = 0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645;
+const PCG_DEFAULT_INCREMENT_128: u128 = 0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f;
+/// The 64-bit multiplier used by the "cheap multiplier" variants, like DXSM.
+const PCG_CHEAP_MULTIPLIER_128: u64 = 0xda94_2042_e4dd_58b5;
 
-/// An instance of the Pcg64 random number generator.
+/// Steps the LCG `state * multiplier + increment` forward `delta` times, in `O(log delta)` time.
+///
+/// This is Brown's algorithm from "Random Number Generation with Arbitrary Strides",
+/// which builds up the combined multiplier and increment by repeated squaring.
+pub(crate) const fn lcg_advance(
+	state: u128,
+	mut multiplier: u128,
+	mut increment: u128,
+	mut delta: u128,
+) -> u128 {
+	let mut total_multiplier = 1_u128;
+	let mut total_increment = 0_u128;
+	while delta > 0 {
+		if delta & 1 == 1 {
+			total_multiplier = total_multiplier.wrapping_mul(multiplier);
+			total_increment = total_increment
+				.wrapping_mul(multiplier)
+				.wrapping_add(increment);
+		}
+		increment = multiplier.wrapping_add(1).wrapping_mul(increment);
+		multiplier = multiplier.wrapping_mul(multiplier);
+		delta >>= 1;
+	}
+	total_multiplier
+		.wrapping_mul(state)
+		.wrapping_add(total_increment)
+}
+
+/// The XSL RR output function: xorshift the high half into the low half, then rotate by the top 6 bits.
+#[inline(always)]
+const fn output_xsl_rr(state: u128) -> u64 {
+	let rotation = (state >> 122) as u32;
+	((state >> 64) as u64 ^ state as u64).rotate_right(rotation)
+}
+
+/// The DXSM output function: "double xorshift multiply", which mixes far better than XSL RR,
+/// making up for the weaker multiplier.
+#[inline(always)]
+const fn output_dxsm(state: u128) -> u64 {
+	let mut high = (state >> 64) as u64;
+	let low = state as u64 | 1;
+	high ^= high >> 32;
+	high = high.wrapping_mul(PCG_CHEAP_MULTIPLIER_128);
+	high ^= high >> 48;
+	high.wrapping_mul(low)
+}
+
+/// Turns a stream number into an LCG increment, which has to be odd.
+const fn stream_increment(stream: u128) -> u128 {
+	(stream << 1) | 1
+}
+
+/// Reads a random `u128` from the system's default source of entropy.
+#[cfg(any(feature = "entropy", feature = "getrandom"))]
+fn entropy_u128() -> u128 {
+	let mut entropy: [u8; core::mem::size_of::<u128>()] = Default::default();
+	crate::entropy::system(&mut entropy);
+	u128::from_le_bytes(entropy)
+}
+
+/// An instance of the Pcg64 random number generator, which is PCG XSL RR 128/64:
+/// a 128-bit LCG with one of 2^127 selectable streams, and a 64-bit output.
+///
+/// This matches `pcg64` from the PCG reference implementations, and `Pcg64` from `rand_pcg`.
 /// Seeded from the system entropy generator when available.
 /// **This generator is _NOT_ cryptographically secure.**
+///
+/// ```rust
+/// use nanorand::{Pcg64, Rng};
+///
+/// // Each stream is a different sequence of numbers, even with the same seed.
+/// let mut first = Pcg64::new_stream(42, 1);
+/// let mut second = Pcg64::new_stream(42, 2);
+/// assert_ne!(first.generate::<u64>(), second.generate::<u64>());
+/// ```
 #[cfg_attr(feature = "zeroize", derive(Zeroize))]
 #[cfg_attr(feature = "zeroize", zeroize(drop))]
 pub struct Pcg64 {
-	seed: u128,
 	state: u128,
-	inc: u128,
+	increment: u128,
 }
 
 impl Pcg64 {
-	/// Create a new [`Pcg64`] instance, seeding from the system's default source of entropy.
+	/// Create a new [`Pcg64`] instance, with a random seed and stream from the system's default source of entropy.
 	#[cfg(any(feature = "entropy", feature = "getrandom"))]
 	#[must_use]
 	pub fn new() -> Self {
-		let mut entropy: [u8; core::mem::size_of::<u128>()] = Default::default();
-		crate::entropy::system(&mut entropy);
-		Self {
-			seed: u128::from_ne_bytes(entropy),
-			inc: 0,
-			state: 0,
-		}
+		Self::default()
 	}
 
-	/// Create a new [`Pcg64`] instance, using a provided seed.
+	/// Create a new [`Pcg64`] instance, using a provided seed and the default stream.
 	#[must_use]
 	pub const fn new_seed(seed: u128) -> Self {
-		Self {
-			seed,
-			inc: 0,
-			state: 0,
-		}
+		Self::from_state_increment(seed, PCG_DEFAULT_INCREMENT_128)
 	}
 
-	fn step(&mut self) {
-		self.state = self
-			.state
+	/// Create a new [`Pcg64`] instance, using a provided seed and stream.
+	/// Only the low 127 bits of `stream` are used.
+	#[must_use]
+	pub const fn new_stream(seed: u128, stream: u128) -> Self {
+		Self::from_state_increment(seed, stream_increment(stream))
+	}
+
+	const fn from_state_increment(seed: u128, increment: u128) -> Self {
+		// This is the reference seeding procedure: step from zero, add the seed, and step again.
+		let state = increment
+			.wrapping_add(seed)
 			.wrapping_mul(PCG_DEFAULT_MULTIPLIER_128)
-			.wrapping_add(self.inc);
+			.wrapping_add(increment);
+		Self { state, increment }
+	}
+
+	/// Skips ahead `delta` outputs, as if [`Rng::rand`] had been called `delta` times, in `O(log delta)` time.
+	pub fn advance(&mut self, delta: u128) {
+		self.state = lcg_advance(
+			self.state,
+			PCG_DEFAULT_MULTIPLIER_128,
+			self.increment,
+			delta,
+		);
+	}
+
+	/// Skips ahead 2^64 outputs.
+	///
+	/// This can be used to hand out up to 2^64 non-overlapping pieces of the same stream;
+	/// if you don't need them to share a stream, [`Pcg64::new_stream`] is simpler.
+	pub fn jump(&mut self) {
+		self.advance(1 << 64);
 	}
 
-	fn rand128(&mut self) -> u64 {
-		self.state = 0;
-		self.inc = self.seed.wrapping_shl(1) | 1;
-		self.step();
-		self.state = self.state.wrapping_add(self.seed);
-		self.step();
-		self.step();
-		self.state.wrapping_shr(64) as u64 ^ self.state as u64
+	/// Skips ahead 2^96 outputs.
+	///
+	/// This can be used to hand out up to 2^32 starting points, each of which can be split further with [`Pcg64::jump`].
+	pub fn long_jump(&mut self) {
+		self.advance(1 << 96);
 	}
 }
 
 #[cfg(any(feature = "entropy", feature = "getrandom"))]
 impl Default for Pcg64 {
-	/// Create a new [`Pcg64`] instance, seeding from the system's default source of entropy.
+	/// Create a new [`Pcg64`] instance, with a random seed and stream from the system's default source of entropy.
 	fn default() -> Self {
-		let mut entropy: [u8; core::mem::size_of::<u128>()] = Default::default();
-		crate::entropy::system(&mut entropy);
-		Self {
-			seed: u128::from_ne_bytes(entropy),
-			inc: 0,
-			state: 0,
-		}
+		Self::new_stream(entropy_u128(), entropy_u128())
 	}
 }
 
 impl Rng<8> for Pcg64 {
 	fn rand(&mut self) -> [u8; 8] {
-		let ret = self.rand128();
-		self.seed = self.state ^ (ret as u128).wrapping_shr(64);
-		ret.to_ne_bytes()
+		self.state = self
+			.state
+			.wrapping_mul(PCG_DEFAULT_MULTIPLIER_128)
+			.wrapping_add(self.increment);
+		output_xsl_rr(self.state).to_le_bytes()
 	}
 }
 
 impl SeedableRng<16, 8> for Pcg64 {
+	/// Create a new [`Pcg64`] instance from a seed, using the default stream.
+	fn from_seed(seed: [u8; 16]) -> Self {
+		Self::new_seed(u128::from_le_bytes(seed))
+	}
+
+	/// Restarts the current stream from a new seed.
 	fn reseed(&mut self, seed: [u8; 16]) {
-		self.seed = u128::from_ne_bytes(seed);
+		*self = Self::from_state_increment(u128::from_le_bytes(seed), self.increment);
 	}
 }
 
 impl Clone for Pcg64 {
 	fn clone(&self) -> Self {
 		Self {
-			seed: self.seed,
-			inc: self.inc,
 			state: self.state,
+			increment: self.increment,
 		}
 	}
 }
@@ -107,9 +196,321 @@ impl Display for Pcg64 {
 impl Debug for Pcg64 {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		f.debug_struct("Pcg64")
-			.field("seed", &format_args!("0x{:x}", self.seed))
 			.field("state", &format_args!("0x{:x}", self.state))
-			.field("inc", &format_args!("0x{:x}", self.inc))
+			.field("increment", &format_args!("0x{:x}", self.increment))
+			.finish()
+	}
+}
+
+/// An instance of the Pcg64Mcg random number generator, which is PCG XSL RR 128/64 (MCG):
+/// a 128-bit multiplicative generator, which is a little faster than [`Pcg64`], but has no streams,
+/// and a period of 2^126.
+///
+/// This matches `pcg64_fast` from the PCG reference implementations, and `Pcg64Mcg` from `rand_pcg`.
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[cfg_attr(feature = "zeroize", zeroize(drop))]
+pub struct Pcg64Mcg {
+	state: u128,
+}
+
+impl Pcg64Mcg {
+	/// Create a new [`Pcg64Mcg`] instance, seeding from the system's default source of entropy.
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a new [`Pcg64Mcg`] instance, using a provided seed.
+	/// The lowest bit of the state must be set, so seeds which only differ in their lowest bit are the same.
+	#[must_use]
+	pub const fn new_seed(seed: u128) -> Self {
+		Self { state: seed | 1 }
+	}
+
+	/// Skips ahead `delta` outputs, as if [`Rng::rand`] had been called `delta` times, in `O(log delta)` time.
+	pub fn advance(&mut self, delta: u128) {
+		self.state = lcg_advance(self.state, PCG_DEFAULT_MULTIPLIER_128, 0, delta);
+	}
+
+	/// Skips ahead 2^64 outputs.
+	///
+	/// This can be used to hand out up to 2^62 non-overlapping streams, such as one for each thread,
+	/// by cloning the generator and jumping the original each time.
+	pub fn jump(&mut self) {
+		self.advance(1 << 64);
+	}
+
+	/// Skips ahead 2^96 outputs.
+	///
+	/// This can be used to hand out up to 2^30 starting points, each of which can be split further with [`Pcg64Mcg::jump`].
+	pub fn long_jump(&mut self) {
+		self.advance(1 << 96);
+	}
+}
+
+#[cfg(any(feature = "entropy", feature = "getrandom"))]
+impl Default for Pcg64Mcg {
+	/// Create a new [`Pcg64Mcg`] instance, seeding from the system's default source of entropy.
+	fn default() -> Self {
+		Self::new_seed(entropy_u128())
+	}
+}
+
+impl Rng<8> for Pcg64Mcg {
+	fn rand(&mut self) -> [u8; 8] {
+		self.state = self.state.wrapping_mul(PCG_DEFAULT_MULTIPLIER_128);
+		output_xsl_rr(self.state).to_le_bytes()
+	}
+}
+
+impl SeedableRng<16, 8> for Pcg64Mcg {
+	fn from_seed(seed: [u8; 16]) -> Self {
+		Self::new_seed(u128::from_le_bytes(seed))
+	}
+}
+
+impl Clone for Pcg64Mcg {
+	fn clone(&self) -> Self {
+		Self { state: self.state }
+	}
+}
+
+impl Display for Pcg64Mcg {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "Pcg64Mcg ({:p})", self)
+	}
+}
+
+impl Debug for Pcg64Mcg {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Pcg64Mcg")
+			.field("state", &format_args!("0x{:x}", self.state))
 			.finish()
 	}
 }
+
+/// An instance of the Pcg64Dxsm random number generator, which is PCG DXSM 128/64 (cheap multiplier):
+/// a 128-bit LCG with a 64-bit multiplier and one of 2^127 selectable streams, and a stronger output function than [`Pcg64`].
+///
+/// This matches `pcg64_dxsm` from the C++ PCG reference implementation, and `PCG64DXSM` from NumPy.
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[cfg_attr(feature = "zeroize", zeroize(drop))]
+pub struct Pcg64Dxsm {
+	state: u128,
+	increment: u128,
+}
+
+impl Pcg64Dxsm {
+	/// Create a new [`Pcg64Dxsm`] instance, with a random seed and stream from the system's default source of entropy.
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a new [`Pcg64Dxsm`] instance, using a provided seed and the default stream.
+	#[must_use]
+	pub const fn new_seed(seed: u128) -> Self {
+		Self::from_state_increment(seed, PCG_DEFAULT_INCREMENT_128)
+	}
+
+	/// Create a new [`Pcg64Dxsm`] instance, using a provided seed and stream.
+	/// Only the low 127 bits of `stream` are used.
+	#[must_use]
+	pub const fn new_stream(seed: u128, stream: u128) -> Self {
+		Self::from_state_increment(seed, stream_increment(stream))
+	}
+
+	const fn from_state_increment(seed: u128, increment: u128) -> Self {
+		let state = increment
+			.wrapping_add(seed)
+			.wrapping_mul(PCG_CHEAP_MULTIPLIER_128 as u128)
+			.wrapping_add(increment);
+		Self { state, increment }
+	}
+
+	/// Skips ahead `delta` outputs, as if [`Rng::rand`] had been called `delta` times, in `O(log delta)` time.
+	pub fn advance(&mut self, delta: u128) {
+		self.state = lcg_advance(
+			self.state,
+			PCG_CHEAP_MULTIPLIER_128 as u128,
+			self.increment,
+			delta,
+		);
+	}
+
+	/// Skips ahead 2^64 outputs.
+	///
+	/// This can be used to hand out up to 2^64 non-overlapping pieces of the same stream;
+	/// if you don't need them to share a stream, [`Pcg64Dxsm::new_stream`] is simpler.
+	pub fn jump(&mut self) {
+		self.advance(1 << 64);
+	}
+
+	/// Skips ahead 2^96 outputs.
+	///
+	/// This can be used to hand out up to 2^32 starting points, each of which can be split further with [`Pcg64Dxsm::jump`].
+	pub fn long_jump(&mut self) {
+		self.advance(1 << 96);
+	}
+}
+
+#[cfg(any(feature = "entropy", feature = "getrandom"))]
+impl Default for Pcg64Dxsm {
+	/// Create a new [`Pcg64Dxsm`] instance, with a random seed and stream from the system's default source of entropy.
+	fn default() -> Self {
+		Self::new_stream(entropy_u128(), entropy_u128())
+	}
+}
+
+impl Rng<8> for Pcg64Dxsm {
+	fn rand(&mut self) -> [u8; 8] {
+		// Unlike the other variants, DXSM outputs the state from before the step,
+		// so the multiplication can happen in parallel with the output function.
+		let output = output_dxsm(self.state);
+		self.state = self
+			.state
+			.wrapping_mul(PCG_CHEAP_MULTIPLIER_128 as u128)
+			.wrapping_add(self.increment);
+		output.to_le_bytes()
+	}
+}
+
+impl SeedableRng<16, 8> for Pcg64Dxsm {
+	/// Create a new [`Pcg64Dxsm`] instance from a seed, using the default stream.
+	fn from_seed(seed: [u8; 16]) -> Self {
+		Self::new_seed(u128::from_le_bytes(seed))
+	}
+
+	/// Restarts the current stream from a new seed.
+	fn reseed(&mut self, seed: [u8; 16]) {
+		*self = Self::from_state_increment(u128::from_le_bytes(seed), self.increment);
+	}
+}
+
+impl Clone for Pcg64Dxsm {
+	fn clone(&self) -> Self {
+		Self {
+			state: self.state,
+			increment: self.increment,
+		}
+	}
+}
+
+impl Display for Pcg64Dxsm {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "Pcg64Dxsm ({:p})", self)
+	}
+}
+
+impl Debug for Pcg64Dxsm {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Pcg64Dxsm")
+			.field("state", &format_args!("0x{:x}", self.state))
+			.field("increment", &format_args!("0x{:x}", self.increment))
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn outputs<Generator: Rng<8>>(mut rng: Generator) -> [u64; 6] {
+		let mut outputs = [0; 6];
+		for output in &mut outputs {
+			*output = u64::from_le_bytes(rng.rand());
+		}
+		outputs
+	}
+
+	#[test]
+	fn ensure_pcg64_matches_reference() {
+		// From pcg-c's check-pcg64 test, with seed 42 and stream 54.
+		assert_eq!(
+			outputs(Pcg64::new_stream(42, 54)),
+			[
+				0x86b1da1d72062b68,
+				0x1304aa46c9853d39,
+				0xa3670e9e0dd50358,
+				0xf9090e529a7dae00,
+				0xc85b9fd837996f2c,
+				0x606121f8e3919196,
+			]
+		);
+		assert_eq!(
+			outputs(Pcg64::new_seed(42)),
+			outputs(Pcg64::new_stream(42, PCG_DEFAULT_INCREMENT_128 >> 1))
+		);
+	}
+
+	#[test]
+	fn ensure_pcg64_mcg_matches_reference() {
+		// From pcg-c's check-pcg64_fast test, with seed 42.
+		assert_eq!(
+			outputs(Pcg64Mcg::new_seed(42)),
+			[
+				0x63b4a3a813ce700a,
+				0x382954200617ab24,
+				0xa7fd85ae3fe950ce,
+				0xd715286aa2887737,
+				0x60c92fee2e59f32c,
+				0x84c4e96beff30017,
+			]
+		);
+	}
+
+	#[test]
+	fn ensure_pcg64_dxsm_matches_reference() {
+		// Seed 42 and stream 54, as with the other variants.
+		assert_eq!(
+			outputs(Pcg64Dxsm::new_stream(42, 54)),
+			[
+				0xf0847c9518bddb90,
+				0x8e7d5f5514ba8aaa,
+				0x86fbd36f8028f6fd,
+				0x8d14b6edbe9f740a,
+				0xa85b2896c7cad55d,
+				0x8ca3894a1d9227bb,
+			]
+		);
+	}
+
+	#[test]
+	fn ensure_advance_matches_stepping() {
+		fn check<Generator: Rng<8>>(rng: Generator, advance: fn(&mut Generator, u128)) {
+			for delta in [0, 1, 2, 7, 1_000] {
+				let mut stepped = rng.clone();
+				let mut advanced = rng.clone();
+				for _ in 0..delta {
+					stepped.rand();
+				}
+				advance(&mut advanced, delta);
+				assert_eq!(stepped.rand(), advanced.rand());
+			}
+		}
+		check(Pcg64::new_stream(0x5eed, 54), Pcg64::advance);
+		check(Pcg64Mcg::new_seed(0x5eed), Pcg64Mcg::advance);
+		check(Pcg64Dxsm::new_stream(0x5eed, 54), Pcg64Dxsm::advance);
+
+		let mut jumped = Pcg64::new_seed(0x5eed);
+		let mut advanced = jumped.clone();
+		jumped.jump();
+		jumped.long_jump();
+		advanced.advance((1 << 96) + (1 << 64));
+		assert_eq!(jumped.rand(), advanced.rand());
+	}
+
+	#[test]
+	fn ensure_reseeding_keeps_the_stream() {
+		let mut rng = Pcg64::new_stream(1, 54);
+		rng.rand();
+		rng.reseed(42_u128.to_le_bytes());
+		assert_eq!(outputs(rng), outputs(Pcg64::new_stream(42, 54)));
+	}
+}
@@ -0,0 +1,185 @@
+// Based off Robert Kern's C implementation at https://github.com/rkern/pcg64/blob/master/pcg64.c
+
+use super::pcg64::{lcg_advance, PCG_DEFAULT_MULTIPLIER_128};
+use crate::rand::{Rng, SeedableRng};
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Each output replaces the seed with `seed * SEED_MULTIPLIER + SEED_INCREMENT`, as [`Pcg64Legacy::rand128`]
+/// steps an LCG three times from a state derived from the seed.
+const SEED_MULTIPLIER: u128 = PCG_DEFAULT_MULTIPLIER_128
+	.wrapping_mul(PCG_DEFAULT_MULTIPLIER_128)
+	.wrapping_mul(3)
+	.wrapping_add(PCG_DEFAULT_MULTIPLIER_128.wrapping_mul(2))
+	.wrapping_add(2);
+const SEED_INCREMENT: u128 = PCG_DEFAULT_MULTIPLIER_128
+	.wrapping_mul(PCG_DEFAULT_MULTIPLIER_128)
+	.wrapping_add(PCG_DEFAULT_MULTIPLIER_128)
+	.wrapping_add(1);
+
+/// An instance of the random number generator which nanorand called `Pcg64` up to version 0.8.
+///
+/// It's built from a PCG LCG step and the XSL output function, but it rebuilds its state from a seed on every call,
+/// so its output doesn't match any reference PCG implementation. It's kept so that existing seeds produce the same
+/// numbers as before; new code should use [`Pcg64`](super::Pcg64) instead.
+///
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[cfg_attr(feature = "zeroize", zeroize(drop))]
+pub struct Pcg64Legacy {
+	seed: u128,
+	state: u128,
+	inc: u128,
+}
+
+impl Pcg64Legacy {
+	/// Create a new [`Pcg64Legacy`] instance, seeding from the system's default source of entropy.
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	#[must_use]
+	pub fn new() -> Self {
+		let mut entropy: [u8; core::mem::size_of::<u128>()] = Default::default();
+		crate::entropy::system(&mut entropy);
+		Self {
+			seed: u128::from_le_bytes(entropy),
+			inc: 0,
+			state: 0,
+		}
+	}
+
+	/// Create a new [`Pcg64Legacy`] instance, using a provided seed.
+	#[must_use]
+	pub const fn new_seed(seed: u128) -> Self {
+		Self {
+			seed,
+			inc: 0,
+			state: 0,
+		}
+	}
+
+	/// Skips ahead `delta` outputs, as if [`Rng::rand`] had been called `delta` times, in `O(log delta)` time.
+	pub fn advance(&mut self, delta: u128) {
+		self.seed = lcg_advance(self.seed, SEED_MULTIPLIER, SEED_INCREMENT, delta);
+	}
+
+	/// Skips ahead 2^64 outputs.
+	///
+	/// This can be used to hand out up to 2^32 non-overlapping streams, such as one for each thread,
+	/// by cloning the generator and jumping the original each time.
+	pub fn jump(&mut self) {
+		self.advance(1 << 64);
+	}
+
+	/// Skips ahead 2^96 outputs.
+	///
+	/// This can be used to hand out up to 2^30 starting points, each of which can be split further with [`Pcg64Legacy::jump`].
+	pub fn long_jump(&mut self) {
+		self.advance(1 << 96);
+	}
+
+	fn step(&mut self) {
+		self.state = self
+			.state
+			.wrapping_mul(PCG_DEFAULT_MULTIPLIER_128)
+			.wrapping_add(self.inc);
+	}
+
+	fn rand128(&mut self) -> u64 {
+		self.state = 0;
+		self.inc = self.seed.wrapping_shl(1) | 1;
+		self.step();
+		self.state = self.state.wrapping_add(self.seed);
+		self.step();
+		self.step();
+		self.state.wrapping_shr(64) as u64 ^ self.state as u64
+	}
+}
+
+#[cfg(any(feature = "entropy", feature = "getrandom"))]
+impl Default for Pcg64Legacy {
+	/// Create a new [`Pcg64Legacy`] instance, seeding from the system's default source of entropy.
+	fn default() -> Self {
+		let mut entropy: [u8; core::mem::size_of::<u128>()] = Default::default();
+		crate::entropy::system(&mut entropy);
+		Self {
+			seed: u128::from_le_bytes(entropy),
+			inc: 0,
+			state: 0,
+		}
+	}
+}
+
+impl Rng<8> for Pcg64Legacy {
+	fn rand(&mut self) -> [u8; 8] {
+		let ret = self.rand128();
+		self.seed = self.state ^ (ret as u128).wrapping_shr(64);
+		ret.to_le_bytes()
+	}
+}
+
+impl SeedableRng<16, 8> for Pcg64Legacy {
+	fn from_seed(seed: [u8; 16]) -> Self {
+		Self::new_seed(u128::from_le_bytes(seed))
+	}
+}
+
+impl Clone for Pcg64Legacy {
+	fn clone(&self) -> Self {
+		Self {
+			seed: self.seed,
+			inc: self.inc,
+			state: self.state,
+		}
+	}
+}
+
+impl Display for Pcg64Legacy {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "Pcg64Legacy ({:p})", self)
+	}
+}
+
+impl Debug for Pcg64Legacy {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Pcg64Legacy")
+			.field("seed", &format_args!("0x{:x}", self.seed))
+			.field("state", &format_args!("0x{:x}", self.state))
+			.field("inc", &format_args!("0x{:x}", self.inc))
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ensure_advance_matches_stepping() {
+		for delta in [0, 1, 2, 7, 1_000] {
+			let mut stepped = Pcg64Legacy::new_seed(0x5eed);
+			let mut advanced = stepped.clone();
+			for _ in 0..delta {
+				stepped.rand();
+			}
+			advanced.advance(delta);
+			assert_eq!(stepped.rand(), advanced.rand());
+		}
+	}
+
+	#[test]
+	fn ensure_jumps_compose() {
+		let mut jumped = Pcg64Legacy::new_seed(0x5eed);
+		let mut advanced = jumped.clone();
+		jumped.jump();
+		jumped.long_jump();
+		jumped.jump();
+		advanced.advance((1 << 96) + (1 << 65));
+		assert_eq!(jumped.rand(), advanced.rand());
+
+		let mut rng = Pcg64Legacy::new_seed(0x5eed);
+		let mut jumped = rng.clone();
+		jumped.jump();
+		assert_ne!(rng.rand(), jumped.rand());
+	}
+}
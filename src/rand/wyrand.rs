@@ -5,6 +5,8 @@ use core::fmt::{self, Debug, Display, Formatter};
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+const WY_INCREMENT: u64 = 0xa0761d6478bd642f;
+
 /// An instance of the WyRand random number generator.
 /// Seeded from the system entropy generator when available.
 /// **This generator is _NOT_ cryptographically secure.**
@@ -27,6 +29,44 @@ impl WyRand {
 	pub const fn new_seed(seed: u64) -> Self {
 		Self { seed }
 	}
+
+	/// Skips ahead `delta` outputs, as if [`Rng::rand`] had been called `delta` times, in constant time.
+	///
+	/// [`WyRand`] repeats itself after 2^64 outputs, so only the low 64 bits of `delta` matter.
+	pub fn advance(&mut self, delta: u128) {
+		// The state is just a counter, so stepping it n times is the same as adding n increments.
+		self.seed = self
+			.seed
+			.wrapping_add(WY_INCREMENT.wrapping_mul(delta as u64));
+	}
+
+	/// Skips ahead 2^32 outputs.
+	///
+	/// This can be used to hand out up to 2^32 non-overlapping streams, such as one for each thread,
+	/// by cloning the generator and jumping the original each time.
+	///
+	/// ```rust
+	/// use nanorand::{Rng, WyRand};
+	///
+	/// let mut rng = WyRand::new_seed(42);
+	/// let workers: Vec<WyRand> = (0..4)
+	///     .map(|_| {
+	///         let worker = rng.clone();
+	///         rng.jump();
+	///         worker
+	///     })
+	///     .collect();
+	/// ```
+	pub fn jump(&mut self) {
+		self.advance(1 << 32);
+	}
+
+	/// Skips ahead 2^48 outputs.
+	///
+	/// This can be used to hand out up to 2^16 starting points, each of which can be split further with [`WyRand::jump`].
+	pub fn long_jump(&mut self) {
+		self.advance(1 << 48);
+	}
 }
 
 #[cfg(any(feature = "entropy", feature = "getrandom"))]
@@ -36,17 +76,17 @@ impl Default for WyRand {
 		let mut entropy: [u8; core::mem::size_of::<u64>()] = Default::default();
 		crate::entropy::system(&mut entropy);
 		Self {
-			seed: u64::from_ne_bytes(entropy),
+			seed: u64::from_le_bytes(entropy),
 		}
 	}
 }
 
 impl Rng<8> for WyRand {
 	fn rand(&mut self) -> [u8; 8] {
-		self.seed = self.seed.wrapping_add(0xa0761d6478bd642f);
+		self.seed = self.seed.wrapping_add(WY_INCREMENT);
 		let t: u128 = (self.seed as u128).wrapping_mul((self.seed ^ 0xe7037ed1a0b428db) as u128);
 		let ret = (t.wrapping_shr(64) ^ t) as u64;
-		ret.to_ne_bytes()
+		ret.to_le_bytes()
 	}
 }
 
@@ -71,7 +111,50 @@ impl Debug for WyRand {
 }
 
 impl SeedableRng<8, 8> for WyRand {
-	fn reseed(&mut self, seed: [u8; 8]) {
-		self.seed = u64::from_ne_bytes(seed);
+	fn from_seed(seed: [u8; 8]) -> Self {
+		Self::new_seed(u64::from_le_bytes(seed))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ensure_advance_matches_stepping() {
+		for delta in [0, 1, 2, 7, 1_000] {
+			let mut stepped = WyRand::new_seed(0x5eed);
+			let mut advanced = stepped.clone();
+			for _ in 0..delta {
+				stepped.rand();
+			}
+			advanced.advance(delta);
+			assert_eq!(stepped.rand(), advanced.rand());
+		}
+
+		// The period is 2^64, so going all the way round should do nothing.
+		let mut rng = WyRand::new_seed(0x5eed);
+		let mut wrapped = rng.clone();
+		wrapped.advance(1 << 64);
+		assert_eq!(rng.rand(), wrapped.rand());
+	}
+
+	#[test]
+	fn ensure_jumps_compose() {
+		let mut jumped = WyRand::new_seed(0x5eed);
+		let mut advanced = jumped.clone();
+		for _ in 0..1 << 16 {
+			jumped.jump();
+		}
+		jumped.long_jump();
+		advanced.advance(1 << 49);
+		assert_eq!(jumped.rand(), advanced.rand());
+	}
+
+	#[test]
+	fn ensure_output_is_little_endian() {
+		// The first output for this seed is 0xae4a7cbfdda9b434, on every target.
+		let mut rng = WyRand::new_seed(42);
+		assert_eq!(rng.rand(), [0x34, 0xb4, 0xa9, 0xdd, 0xbf, 0x7c, 0x4a, 0xae]);
 	}
 }
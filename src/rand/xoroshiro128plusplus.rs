@@ -0,0 +1,192 @@
+// Based off Vigna's C implementation at https://prng.di.unimi.it/xoroshiro128plusplus.c
+
+use crate::rand::{Rng, SeedableRng};
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The state used in place of an all-zero seed, which would only ever produce zeros.
+/// This is the first two outputs of SplitMix64 seeded with zero, the same as `rand_xoshiro` uses.
+const ZERO_SEED_STATE: [u64; 2] = [0xe220a8397b1dcdaf, 0x6e789e6aa1b965f4];
+
+/// An instance of the xoroshiro128++ random number generator, which has 128 bits of state and a period of 2^128 - 1.
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+///
+/// ```rust
+/// use nanorand::{Rng, Xoroshiro128PlusPlus};
+///
+/// let mut rng = Xoroshiro128PlusPlus::new_seed([1, 2]);
+/// assert_eq!(rng.generate::<u64>(), 393217);
+/// ```
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[cfg_attr(feature = "zeroize", zeroize(drop))]
+pub struct Xoroshiro128PlusPlus {
+	state: [u64; 2],
+}
+
+impl Xoroshiro128PlusPlus {
+	/// Create a new [`Xoroshiro128PlusPlus`] instance, seeding from the system's default source of entropy.
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a new [`Xoroshiro128PlusPlus`] instance, using a provided seed as the state.
+	///
+	/// The state can't be all zeros, so that seed is replaced with a fixed, non-zero state.
+	#[must_use]
+	pub const fn new_seed(seed: [u64; 2]) -> Self {
+		if seed[0] | seed[1] == 0 {
+			Self {
+				state: ZERO_SEED_STATE,
+			}
+		} else {
+			Self { state: seed }
+		}
+	}
+
+	fn step(&mut self) {
+		let [s0, mut s1] = self.state;
+		s1 ^= s0;
+		self.state = [s0.rotate_left(49) ^ s1 ^ (s1 << 21), s1.rotate_left(28)];
+	}
+
+	/// Replaces the state with the XOR of the states reached at each step set in `polynomial`,
+	/// which is the same as advancing the generator by a fixed number of steps.
+	fn jump_polynomial(&mut self, polynomial: [u64; 2]) {
+		let mut jumped = [0; 2];
+		for word in polynomial {
+			for bit in 0..64 {
+				if (word >> bit) & 1 == 1 {
+					for (jumped, state) in jumped.iter_mut().zip(self.state) {
+						*jumped ^= state;
+					}
+				}
+				self.step();
+			}
+		}
+		self.state = jumped;
+	}
+
+	/// Skips ahead 2^64 outputs.
+	///
+	/// This can be used to hand out up to 2^64 non-overlapping streams, such as one for each thread,
+	/// by cloning the generator and jumping the original each time.
+	pub fn jump(&mut self) {
+		self.jump_polynomial([0x2bd7a6a6e99c2ddc, 0x0992ccaf6a6fca05]);
+	}
+
+	/// Skips ahead 2^96 outputs.
+	///
+	/// This can be used to hand out up to 2^32 starting points, each of which can be split further with [`Xoroshiro128PlusPlus::jump`].
+	pub fn long_jump(&mut self) {
+		self.jump_polynomial([0x360fd5f2cf8d5d99, 0x9c6e6877736c46e3]);
+	}
+}
+
+#[cfg(any(feature = "entropy", feature = "getrandom"))]
+impl Default for Xoroshiro128PlusPlus {
+	/// Create a new [`Xoroshiro128PlusPlus`] instance, seeding from the system's default source of entropy.
+	fn default() -> Self {
+		let mut entropy: [u8; 16] = Default::default();
+		crate::entropy::system(&mut entropy);
+		Self::from_seed(entropy)
+	}
+}
+
+impl Rng<8> for Xoroshiro128PlusPlus {
+	fn rand(&mut self) -> [u8; 8] {
+		let ret = self.state[0]
+			.wrapping_add(self.state[1])
+			.rotate_left(17)
+			.wrapping_add(self.state[0]);
+		self.step();
+		ret.to_le_bytes()
+	}
+}
+
+impl SeedableRng<16, 8> for Xoroshiro128PlusPlus {
+	fn from_seed(seed: [u8; 16]) -> Self {
+		let mut state = [0_u64; 2];
+		for (word, bytes) in state.iter_mut().zip(seed.chunks_exact(8)) {
+			let mut buffer = [0_u8; 8];
+			buffer.copy_from_slice(bytes);
+			*word = u64::from_le_bytes(buffer);
+		}
+		Self::new_seed(state)
+	}
+}
+
+impl Clone for Xoroshiro128PlusPlus {
+	fn clone(&self) -> Self {
+		Self { state: self.state }
+	}
+}
+
+impl Display for Xoroshiro128PlusPlus {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "Xoroshiro128PlusPlus ({:p})", self)
+	}
+}
+
+impl Debug for Xoroshiro128PlusPlus {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Xoroshiro128PlusPlus")
+			.field("state", &format_args!("{:x?}", self.state))
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn outputs(mut rng: Xoroshiro128PlusPlus) -> [u64; 10] {
+		let mut outputs = [0; 10];
+		for output in &mut outputs {
+			*output = u64::from_le_bytes(rng.rand());
+		}
+		outputs
+	}
+
+	#[test]
+	fn ensure_xoroshiro128plusplus_matches_reference() {
+		// From Vigna's reference implementation, with the state set to [1, 2].
+		assert_eq!(
+			outputs(Xoroshiro128PlusPlus::new_seed([1, 2])),
+			[
+				393217,
+				669327710093319,
+				1732421326133921491,
+				11394790081659126983,
+				9555452776773192676,
+				3586421180005889563,
+				1691397964866707553,
+				10735626796753111697,
+				15216282715349408991,
+				14247243556711267923,
+			]
+		);
+	}
+
+	#[test]
+	fn ensure_xoroshiro128plusplus_jumps_match_reference() {
+		// The same states as `rand_xoshiro` reaches, which uses Vigna's jump polynomials.
+		let mut rng = Xoroshiro128PlusPlus::new_seed([1, 2]);
+		rng.jump();
+		assert_eq!(rng.state, [8625214420338730171, 17730401117375794498]);
+		let mut rng = Xoroshiro128PlusPlus::new_seed([1, 2]);
+		rng.long_jump();
+		assert_eq!(rng.state, [2219032219420146153, 9655216301158529667]);
+	}
+
+	#[test]
+	fn ensure_zero_seed_is_replaced() {
+		let mut rng = Xoroshiro128PlusPlus::new_seed([0; 2]);
+		assert_eq!(outputs(rng.clone())[0], 0x6f68e1e7e2646ee1);
+		rng.reseed([0; 16]);
+		assert_eq!(rng.state, ZERO_SEED_STATE);
+	}
+}
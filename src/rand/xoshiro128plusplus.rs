@@ -0,0 +1,200 @@
+// Based off Vigna's C implementation at https://prng.di.unimi.it/xoshiro128plusplus.c
+
+use crate::rand::{Rng, SeedableRng};
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The state used in place of an all-zero seed, which would only ever produce zeros.
+/// This is the first two outputs of SplitMix64 seeded with zero, split into 32-bit words,
+/// the same as `rand_xoshiro` uses.
+const ZERO_SEED_STATE: [u32; 4] = [0x7b1dcdaf, 0xe220a839, 0xa1b965f4, 0x6e789e6a];
+
+/// An instance of the xoshiro128++ random number generator, which has 128 bits of state and a period of 2^128 - 1.
+/// It only uses 32-bit arithmetic, so it's a good fit for 32-bit targets.
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+///
+/// ```rust
+/// use nanorand::{Rng, Xoshiro128PlusPlus};
+///
+/// let mut rng = Xoshiro128PlusPlus::new_seed([1, 2, 3, 4]);
+/// assert_eq!(rng.generate::<u32>(), 641);
+/// ```
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[cfg_attr(feature = "zeroize", zeroize(drop))]
+pub struct Xoshiro128PlusPlus {
+	state: [u32; 4],
+}
+
+impl Xoshiro128PlusPlus {
+	/// Create a new [`Xoshiro128PlusPlus`] instance, seeding from the system's default source of entropy.
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a new [`Xoshiro128PlusPlus`] instance, using a provided seed as the state.
+	///
+	/// The state can't be all zeros, so that seed is replaced with a fixed, non-zero state.
+	#[must_use]
+	pub const fn new_seed(seed: [u32; 4]) -> Self {
+		if seed[0] | seed[1] | seed[2] | seed[3] == 0 {
+			Self {
+				state: ZERO_SEED_STATE,
+			}
+		} else {
+			Self { state: seed }
+		}
+	}
+
+	fn step(&mut self) {
+		let t = self.state[1] << 9;
+		self.state[2] ^= self.state[0];
+		self.state[3] ^= self.state[1];
+		self.state[1] ^= self.state[2];
+		self.state[0] ^= self.state[3];
+		self.state[2] ^= t;
+		self.state[3] = self.state[3].rotate_left(11);
+	}
+
+	/// Replaces the state with the XOR of the states reached at each step set in `polynomial`,
+	/// which is the same as advancing the generator by a fixed number of steps.
+	fn jump_polynomial(&mut self, polynomial: [u32; 4]) {
+		let mut jumped = [0; 4];
+		for word in polynomial {
+			for bit in 0..32 {
+				if (word >> bit) & 1 == 1 {
+					for (jumped, state) in jumped.iter_mut().zip(self.state) {
+						*jumped ^= state;
+					}
+				}
+				self.step();
+			}
+		}
+		self.state = jumped;
+	}
+
+	/// Skips ahead 2^64 outputs.
+	///
+	/// This can be used to hand out up to 2^64 non-overlapping streams, such as one for each thread,
+	/// by cloning the generator and jumping the original each time.
+	pub fn jump(&mut self) {
+		self.jump_polynomial([0x8764000b, 0xf542d2d3, 0x6fa035c3, 0x77f2db5b]);
+	}
+
+	/// Skips ahead 2^96 outputs.
+	///
+	/// This can be used to hand out up to 2^32 starting points, each of which can be split further with [`Xoshiro128PlusPlus::jump`].
+	pub fn long_jump(&mut self) {
+		self.jump_polynomial([0xb523952e, 0x0b6f099f, 0xccf5a0ef, 0x1c580662]);
+	}
+}
+
+#[cfg(any(feature = "entropy", feature = "getrandom"))]
+impl Default for Xoshiro128PlusPlus {
+	/// Create a new [`Xoshiro128PlusPlus`] instance, seeding from the system's default source of entropy.
+	fn default() -> Self {
+		let mut entropy: [u8; 16] = Default::default();
+		crate::entropy::system(&mut entropy);
+		Self::from_seed(entropy)
+	}
+}
+
+impl Rng<4> for Xoshiro128PlusPlus {
+	fn rand(&mut self) -> [u8; 4] {
+		let ret = self.state[0]
+			.wrapping_add(self.state[3])
+			.rotate_left(7)
+			.wrapping_add(self.state[0]);
+		self.step();
+		ret.to_le_bytes()
+	}
+}
+
+impl SeedableRng<16, 4> for Xoshiro128PlusPlus {
+	fn from_seed(seed: [u8; 16]) -> Self {
+		let mut state = [0_u32; 4];
+		for (word, bytes) in state.iter_mut().zip(seed.chunks_exact(4)) {
+			let mut buffer = [0_u8; 4];
+			buffer.copy_from_slice(bytes);
+			*word = u32::from_le_bytes(buffer);
+		}
+		Self::new_seed(state)
+	}
+}
+
+impl Clone for Xoshiro128PlusPlus {
+	fn clone(&self) -> Self {
+		Self { state: self.state }
+	}
+}
+
+impl Display for Xoshiro128PlusPlus {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "Xoshiro128PlusPlus ({:p})", self)
+	}
+}
+
+impl Debug for Xoshiro128PlusPlus {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Xoshiro128PlusPlus")
+			.field("state", &format_args!("{:x?}", self.state))
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn outputs(mut rng: Xoshiro128PlusPlus) -> [u32; 10] {
+		let mut outputs = [0; 10];
+		for output in &mut outputs {
+			*output = u32::from_le_bytes(rng.rand());
+		}
+		outputs
+	}
+
+	#[test]
+	fn ensure_xoshiro128plusplus_matches_reference() {
+		// From Vigna's reference implementation, with the state set to [1, 2, 3, 4].
+		assert_eq!(
+			outputs(Xoshiro128PlusPlus::new_seed([1, 2, 3, 4])),
+			[
+				641, 1573767, 3222811527, 3517856514, 836907274, 4247214768, 3867114732,
+				1355841295, 495546011, 621204420,
+			]
+		);
+	}
+
+	#[test]
+	fn ensure_xoshiro128plusplus_jumps_match_reference() {
+		// The same states as `rand_xoshiro` reaches, which uses Vigna's jump polynomials.
+		let mut rng = Xoshiro128PlusPlus::new_seed([1, 2, 3, 4]);
+		rng.jump();
+		assert_eq!(rng.state, [2843103750, 2038079848, 1533207345, 44816753]);
+		let mut rng = Xoshiro128PlusPlus::new_seed([1, 2, 3, 4]);
+		rng.long_jump();
+		assert_eq!(rng.state, [1611968294, 2125834322, 966769569, 3193880526]);
+	}
+
+	#[test]
+	fn ensure_zero_seed_is_replaced() {
+		let mut rng = Xoshiro128PlusPlus::new_seed([0; 4]);
+		assert_eq!(outputs(rng.clone())[..2], [0x4653daa3, 0x73922b58]);
+		rng.reseed([0; 16]);
+		assert_eq!(rng.state, ZERO_SEED_STATE);
+	}
+
+	#[test]
+	fn ensure_seed_from_u64_matches_reference() {
+		// `rand_xoshiro` also expands the seed with SplitMix64.
+		assert_eq!(Xoshiro128PlusPlus::seed_from_u64(0).state, ZERO_SEED_STATE);
+		assert_eq!(
+			outputs(Xoshiro128PlusPlus::seed_from_u64(42))[..2],
+			[2643743425, 1762251840]
+		);
+	}
+}
@@ -0,0 +1,228 @@
+// Based off Vigna's C implementations at https://prng.di.unimi.it/
+
+use crate::rand::{Rng, SeedableRng};
+use core::{
+	fmt::{self, Debug, Display, Formatter},
+	marker::PhantomData,
+};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The state used in place of an all-zero seed, which would only ever produce zeros.
+/// This is the first four outputs of SplitMix64 seeded with zero, the same as `rand_xoshiro` uses.
+pub(super) const ZERO_SEED_STATE: [u64; 4] = [
+	0xe220a8397b1dcdaf,
+	0x6e789e6aa1b965f4,
+	0x06c45d188009454f,
+	0xf88bb8a8724c81ec,
+];
+
+/// The function that turns a xoshiro256 state into an output, which is all that sets the variants apart.
+pub trait Scrambler {
+	/// The name of the generator, for its [`Display`] and [`Debug`] output.
+	const NAME: &'static str;
+
+	/// Returns the output for `state`, before the generator steps past it.
+	fn scramble(state: &[u64; 4]) -> u64;
+}
+
+/// An instance of a xoshiro256 random number generator, which has 256 bits of state and a period of 2^256 - 1.
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+#[cfg_attr(feature = "zeroize", zeroize(drop))]
+pub struct Xoshiro256<Output: Scrambler> {
+	pub(super) state: [u64; 4],
+	output: PhantomData<Output>,
+}
+
+impl<Output: Scrambler> Xoshiro256<Output> {
+	/// Create a new instance, seeding from the system's default source of entropy.
+	#[cfg(any(feature = "entropy", feature = "getrandom"))]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Create a new instance, using a provided seed as the state.
+	///
+	/// The state can't be all zeros, so that seed is replaced with a fixed, non-zero state.
+	#[must_use]
+	pub const fn new_seed(seed: [u64; 4]) -> Self {
+		let state = if seed[0] | seed[1] | seed[2] | seed[3] == 0 {
+			ZERO_SEED_STATE
+		} else {
+			seed
+		};
+		Self {
+			state,
+			output: PhantomData,
+		}
+	}
+
+	fn step(&mut self) {
+		let t = self.state[1] << 17;
+		self.state[2] ^= self.state[0];
+		self.state[3] ^= self.state[1];
+		self.state[1] ^= self.state[2];
+		self.state[0] ^= self.state[3];
+		self.state[2] ^= t;
+		self.state[3] = self.state[3].rotate_left(45);
+	}
+
+	/// Replaces the state with the XOR of the states reached at each step set in `polynomial`,
+	/// which is the same as advancing the generator by a fixed number of steps.
+	fn jump_polynomial(&mut self, polynomial: [u64; 4]) {
+		let mut jumped = [0; 4];
+		for word in polynomial {
+			for bit in 0..64 {
+				if (word >> bit) & 1 == 1 {
+					for (jumped, state) in jumped.iter_mut().zip(self.state) {
+						*jumped ^= state;
+					}
+				}
+				self.step();
+			}
+		}
+		self.state = jumped;
+	}
+
+	/// Skips ahead 2^128 outputs.
+	///
+	/// This can be used to hand out up to 2^128 non-overlapping streams, such as one for each thread,
+	/// by cloning the generator and jumping the original each time.
+	pub fn jump(&mut self) {
+		self.jump_polynomial([
+			0x180ec6d33cfd0aba,
+			0xd5a61266f0c9392c,
+			0xa9582618e03fc9aa,
+			0x39abdc4529b1661c,
+		]);
+	}
+
+	/// Skips ahead 2^192 outputs.
+	///
+	/// This can be used to hand out up to 2^64 starting points, each of which can be split further with [`Self::jump`].
+	pub fn long_jump(&mut self) {
+		self.jump_polynomial([
+			0x76e15d3efefdcbbf,
+			0xc5004e441c522fb3,
+			0x77710069854ee241,
+			0x39109bb02acbe635,
+		]);
+	}
+}
+
+#[cfg(any(feature = "entropy", feature = "getrandom"))]
+impl<Output: Scrambler> Default for Xoshiro256<Output> {
+	/// Create a new instance, seeding from the system's default source of entropy.
+	fn default() -> Self {
+		let mut entropy: [u8; 32] = Default::default();
+		crate::entropy::system(&mut entropy);
+		Self::from_seed(entropy)
+	}
+}
+
+impl<Output: Scrambler> Rng<8> for Xoshiro256<Output> {
+	fn rand(&mut self) -> [u8; 8] {
+		let ret = Output::scramble(&self.state);
+		self.step();
+		ret.to_le_bytes()
+	}
+}
+
+impl<Output: Scrambler> SeedableRng<32, 8> for Xoshiro256<Output> {
+	fn from_seed(seed: [u8; 32]) -> Self {
+		let mut state = [0_u64; 4];
+		for (word, bytes) in state.iter_mut().zip(seed.chunks_exact(8)) {
+			let mut buffer = [0_u8; 8];
+			buffer.copy_from_slice(bytes);
+			*word = u64::from_le_bytes(buffer);
+		}
+		Self::new_seed(state)
+	}
+}
+
+impl<Output: Scrambler> Clone for Xoshiro256<Output> {
+	fn clone(&self) -> Self {
+		Self {
+			state: self.state,
+			output: PhantomData,
+		}
+	}
+}
+
+impl<Output: Scrambler> Display for Xoshiro256<Output> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{} ({:p})", Output::NAME, self)
+	}
+}
+
+impl<Output: Scrambler> Debug for Xoshiro256<Output> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct(Output::NAME)
+			.field("state", &format_args!("{:x?}", self.state))
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Outputs the first word of the state, so the tests can see the state transition on its own.
+	struct Identity;
+
+	impl Scrambler for Identity {
+		const NAME: &'static str = "Identity";
+
+		fn scramble(state: &[u64; 4]) -> u64 {
+			state[0]
+		}
+	}
+
+	#[test]
+	fn ensure_jumps_match_reference() {
+		// The same states as `rand_xoshiro` reaches, which uses Vigna's jump polynomials.
+		let mut rng = Xoshiro256::<Identity>::new_seed([1, 2, 3, 4]);
+		rng.jump();
+		assert_eq!(
+			rng.state,
+			[
+				10122426448480695249,
+				8079205330032121950,
+				7289065458748526725,
+				9477464255293849680,
+			]
+		);
+		let mut rng = Xoshiro256::<Identity>::new_seed([1, 2, 3, 4]);
+		rng.long_jump();
+		assert_eq!(
+			rng.state,
+			[
+				678511610814637056,
+				15850499779492529430,
+				6002989639035333134,
+				3559352929785830385,
+			]
+		);
+	}
+
+	#[test]
+	fn ensure_zero_seed_is_replaced() {
+		let mut rng = Xoshiro256::<Identity>::new_seed([0; 4]);
+		assert_eq!(rng.state, ZERO_SEED_STATE);
+		rng.rand();
+		rng.reseed([0; 32]);
+		assert_eq!(rng.state, ZERO_SEED_STATE);
+	}
+
+	#[test]
+	fn ensure_seed_from_u64_matches_reference() {
+		// `rand_xoshiro` also expands the seed with SplitMix64.
+		assert_eq!(
+			Xoshiro256::<Identity>::seed_from_u64(0).state,
+			ZERO_SEED_STATE
+		);
+	}
+}
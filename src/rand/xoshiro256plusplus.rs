@@ -0,0 +1,80 @@
+// Based off Vigna's C implementation at https://prng.di.unimi.it/xoshiro256plusplus.c
+
+use super::xoshiro256::{Scrambler, Xoshiro256};
+
+/// The xoshiro256++ output function, which adds the first and last words of the state.
+pub struct PlusPlus;
+
+impl Scrambler for PlusPlus {
+	const NAME: &'static str = "Xoshiro256PlusPlus";
+
+	fn scramble(state: &[u64; 4]) -> u64 {
+		state[0]
+			.wrapping_add(state[3])
+			.rotate_left(23)
+			.wrapping_add(state[0])
+	}
+}
+
+/// An instance of the xoshiro256++ random number generator, which has 256 bits of state and a period of 2^256 - 1.
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+///
+/// ```rust
+/// use nanorand::{Rng, Xoshiro256PlusPlus};
+///
+/// let mut rng = Xoshiro256PlusPlus::new_seed([1, 2, 3, 4]);
+/// assert_eq!(rng.generate::<u64>(), 41943041);
+/// ```
+pub type Xoshiro256PlusPlus = Xoshiro256<PlusPlus>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::rand::{Rng, SeedableRng};
+
+	fn outputs(mut rng: Xoshiro256PlusPlus) -> [u64; 10] {
+		let mut outputs = [0; 10];
+		for output in &mut outputs {
+			*output = u64::from_le_bytes(rng.rand());
+		}
+		outputs
+	}
+
+	#[test]
+	fn ensure_xoshiro256plusplus_matches_reference() {
+		// From Vigna's reference implementation, with the state set to [1, 2, 3, 4].
+		assert_eq!(
+			outputs(Xoshiro256PlusPlus::new_seed([1, 2, 3, 4])),
+			[
+				41943041,
+				58720359,
+				3588806011781223,
+				3591011842654386,
+				9228616714210784205,
+				9973669472204895162,
+				14011001112246962877,
+				12406186145184390807,
+				15849039046786891736,
+				10450023813501588000,
+			]
+		);
+	}
+
+	#[test]
+	fn ensure_zero_seed_output_matches_reference() {
+		assert_eq!(
+			outputs(Xoshiro256PlusPlus::new_seed([0; 4]))[0],
+			0x53175d61490b23df
+		);
+	}
+
+	#[test]
+	fn ensure_seed_from_u64_matches_reference() {
+		// `rand_xoshiro` also expands the seed with SplitMix64.
+		assert_eq!(
+			outputs(Xoshiro256PlusPlus::seed_from_u64(42))[..2],
+			[15021278609987233951, 5881210131331364753]
+		);
+	}
+}
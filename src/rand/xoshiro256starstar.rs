@@ -0,0 +1,68 @@
+// Based off Vigna's C implementation at https://prng.di.unimi.it/xoshiro256starstar.c
+
+use super::xoshiro256::{Scrambler, Xoshiro256};
+
+/// The xoshiro256** output function, which scrambles the second word of the state with two multiplications.
+pub struct StarStar;
+
+impl Scrambler for StarStar {
+	const NAME: &'static str = "Xoshiro256StarStar";
+
+	fn scramble(state: &[u64; 4]) -> u64 {
+		state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+	}
+}
+
+/// An instance of the xoshiro256** random number generator, which has 256 bits of state and a period of 2^256 - 1.
+/// Seeded from the system entropy generator when available.
+/// **This generator is _NOT_ cryptographically secure.**
+///
+/// ```rust
+/// use nanorand::{Rng, Xoshiro256StarStar};
+///
+/// let mut rng = Xoshiro256StarStar::new_seed([1, 2, 3, 4]);
+/// assert_eq!(rng.generate::<u64>(), 11520);
+/// ```
+pub type Xoshiro256StarStar = Xoshiro256<StarStar>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::rand::Rng;
+
+	fn outputs(mut rng: Xoshiro256StarStar) -> [u64; 10] {
+		let mut outputs = [0; 10];
+		for output in &mut outputs {
+			*output = u64::from_le_bytes(rng.rand());
+		}
+		outputs
+	}
+
+	#[test]
+	fn ensure_xoshiro256starstar_matches_reference() {
+		// From Vigna's reference implementation, with the state set to [1, 2, 3, 4].
+		assert_eq!(
+			outputs(Xoshiro256StarStar::new_seed([1, 2, 3, 4])),
+			[
+				11520,
+				0,
+				1509978240,
+				1215971899390074240,
+				1216172134540287360,
+				607988272756665600,
+				16172922978634559625,
+				8476171486693032832,
+				10595114339597558777,
+				2904607092377533576,
+			]
+		);
+	}
+
+	#[test]
+	fn ensure_zero_seed_output_matches_reference() {
+		assert_eq!(
+			outputs(Xoshiro256StarStar::new_seed([0; 4]))[0],
+			0x99ec5f36cb75f2b4
+		);
+	}
+}
@@ -0,0 +1,431 @@
+use crate::Rng;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// Shuffles a slice with the Fisher-Yates algorithm, extracting several indices from each random [`u64`],
+/// as described by Brackett-Rozinsky & Lemire in "Batched Ranged Random Integer Generation".
+pub(crate) fn shuffle<Contents, Generator: Rng<OUTPUT>, const OUTPUT: usize>(
+	rng: &mut Generator,
+	slice: &mut [Contents],
+) {
+	let mut remaining = slice.len();
+	// Large ranges get one index per random number, as batching them would often need to reject.
+	// The limits are compared as `u64`s, as they don't all fit in a 16-bit `usize`.
+	while remaining as u64 > 0x4000_0000 {
+		let index = rng.generate_range(0..remaining);
+		slice.swap(remaining - 1, index);
+		remaining -= 1;
+	}
+	// The smaller the ranges are, the more of them fit into 64 bits.
+	// These limits keep the product of the ranges well under 2^64, so we rarely have to reject anything.
+	shuffle_batches::<_, _, 2, OUTPUT>(rng, slice, &mut remaining, 0x80000);
+	shuffle_batches::<_, _, 3, OUTPUT>(rng, slice, &mut remaining, 0x4000);
+	shuffle_batches::<_, _, 4, OUTPUT>(rng, slice, &mut remaining, 0x800);
+	shuffle_batches::<_, _, 5, OUTPUT>(rng, slice, &mut remaining, 0x200);
+	shuffle_batches::<_, _, 6, OUTPUT>(rng, slice, &mut remaining, 6);
+	// Finish the last few, which can't be batched without going out of bounds.
+	while remaining > 1 {
+		let index = rng.generate_range(0..remaining);
+		slice.swap(remaining - 1, index);
+		remaining -= 1;
+	}
+}
+
+/// Shuffles the last items of `slice[..remaining]` in batches of `BATCH`, until `remaining` is no more than `until`.
+#[inline(always)]
+fn shuffle_batches<Contents, Generator: Rng<OUTPUT>, const BATCH: usize, const OUTPUT: usize>(
+	rng: &mut Generator,
+	slice: &mut [Contents],
+	remaining: &mut usize,
+	until: u64,
+) {
+	while *remaining as u64 > until {
+		shuffle_batch::<_, _, BATCH, OUTPUT>(rng, slice, *remaining);
+		*remaining -= BATCH;
+	}
+}
+
+/// Performs `BATCH` steps of a Fisher-Yates shuffle, on the last items of `slice[..remaining]`.
+#[inline(always)]
+fn shuffle_batch<Contents, Generator: Rng<OUTPUT>, const BATCH: usize, const OUTPUT: usize>(
+	rng: &mut Generator,
+	slice: &mut [Contents],
+	remaining: usize,
+) {
+	let indices = batched_indices::<Generator, BATCH, OUTPUT>(rng, remaining as u64);
+	for (offset, index) in indices.into_iter().enumerate() {
+		slice.swap(remaining - 1 - offset, index as usize);
+	}
+}
+
+/// Generates `BATCH` random indices from a single [`u64`] (where possible), with the first in `0..upper`,
+/// the second in `0..upper - 1`, and so on. The product of all of the ranges must fit in a [`u64`].
+#[inline(always)]
+fn batched_indices<Generator: Rng<OUTPUT>, const BATCH: usize, const OUTPUT: usize>(
+	rng: &mut Generator,
+	upper: u64,
+) -> [u64; BATCH] {
+	let mut indices = [0; BATCH];
+	let mut leftover = extract_indices(rng.generate(), upper, &mut indices);
+	// Like Lemire's method for a single range, anything left over below 2^64 % product is biased,
+	// so try again in that case. Checking against the product first means we rarely have to divide.
+	let mut product = 1_u64;
+	for offset in 0..BATCH as u64 {
+		product *= upper - offset;
+	}
+	if leftover < product {
+		let threshold = product.wrapping_neg() % product;
+		while leftover < threshold {
+			leftover = extract_indices(rng.generate(), upper, &mut indices);
+		}
+	}
+	indices
+}
+
+/// Splits a random number into indices, returning the randomness which is left over.
+#[inline(always)]
+fn extract_indices<const BATCH: usize>(
+	mut random: u64,
+	upper: u64,
+	indices: &mut [u64; BATCH],
+) -> u64 {
+	// Each multiplication moves the next index into the high bits, leaving the rest of the randomness in the low bits.
+	for (offset, index) in indices.iter_mut().enumerate() {
+		let multiplied = u128::from(random) * u128::from(upper - offset as u64);
+		*index = (multiplied >> 64) as u64;
+		random = multiplied as u64;
+	}
+	random
+}
+
+/// An iterator which lazily yields the items of a slice in a random order, without modifying the slice.
+///
+/// Each call to [`Iterator::next`] performs one step of a Fisher-Yates shuffle on a list of indices,
+/// so taking only the first few items is cheap, and every permutation is equally likely.
+///
+/// ```rust
+/// use nanorand::{Rng, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// let songs = ["Intro", "Verse", "Chorus", "Bridge", "Outro"];
+/// for song in rng.shuffled(&songs).take(3) {
+///     println!("Now playing: {}", song);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct ShuffledIter<'a, Contents, Generator: Rng<OUTPUT>, const OUTPUT: usize> {
+	slice: &'a [Contents],
+	indices: Vec<usize>,
+	position: usize,
+	rng: &'a mut Generator,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, Contents, Generator: Rng<OUTPUT>, const OUTPUT: usize>
+	ShuffledIter<'a, Contents, Generator, OUTPUT>
+{
+	/// Creates a new iterator over the items of `slice`, in an order determined by `rng`.
+	pub fn new(slice: &'a [Contents], rng: &'a mut Generator) -> Self {
+		Self {
+			slice,
+			indices: (0..slice.len()).collect(),
+			position: 0,
+			rng,
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, Contents, Generator: Rng<OUTPUT>, const OUTPUT: usize> Iterator
+	for ShuffledIter<'a, Contents, Generator, OUTPUT>
+{
+	type Item = &'a Contents;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.position >= self.indices.len() {
+			return None;
+		}
+		let swap_with = self.rng.generate_range(self.position..self.indices.len());
+		self.indices.swap(self.position, swap_with);
+		let index = self.indices[self.position];
+		self.position += 1;
+		Some(&self.slice[index])
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.indices.len() - self.position;
+		(remaining, Some(remaining))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, Contents, Generator: Rng<OUTPUT>, const OUTPUT: usize> ExactSizeIterator
+	for ShuffledIter<'a, Contents, Generator, OUTPUT>
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, Contents, Generator: Rng<OUTPUT>, const OUTPUT: usize> FusedIterator
+	for ShuffledIter<'a, Contents, Generator, OUTPUT>
+{
+}
+
+/// A pseudorandom permutation of `0..len`, which can be evaluated at any index in constant time,
+/// without allocating anything, no matter how large `len` is.
+///
+/// This is a Feistel network over the smallest power of 4 that's at least `len`, and any outputs
+/// that fall outside of `0..len` are fed back in until they don't ("cycle walking"),
+/// so every index maps to a unique index.
+///
+/// Unlike [`ShuffledIter`], this can't produce every possible permutation with equal probability,
+/// but it's more than random enough for things like visiting IDs or scanning addresses in a random order.
+///
+/// ```rust
+/// use nanorand::{seq::IndexPermutation, WyRand};
+///
+/// let mut rng = WyRand::new();
+/// // Visit 2^40 IDs in a random order, without an 8 TiB lookup table.
+/// let permutation = IndexPermutation::new(&mut rng, 1 << 40);
+/// for id in permutation.take(5) {
+///     assert!(id < 1 << 40);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexPermutation {
+	len: u64,
+	/// The number of bits in each half of the Feistel network's input.
+	half_bits: u32,
+	keys: [u64; ROUNDS],
+	/// The next position to yield, when used as an iterator.
+	position: u64,
+}
+
+/// The number of rounds in the Feistel network.
+const ROUNDS: usize = 6;
+
+impl IndexPermutation {
+	/// Creates a new random permutation of `0..len`, with the keys generated by `rng`.
+	pub fn new<Generator: Rng<OUTPUT>, const OUTPUT: usize>(rng: &mut Generator, len: u64) -> Self {
+		let bits = 64 - len.saturating_sub(1).leading_zeros();
+		Self {
+			len,
+			half_bits: ((bits + 1) / 2).max(1),
+			keys: rng.generate(),
+			position: 0,
+		}
+	}
+
+	/// The number of indices in this permutation.
+	#[allow(clippy::len_without_is_empty)]
+	pub const fn len(&self) -> u64 {
+		self.len
+	}
+
+	/// Gets the index that `index` is mapped to, or [`None`] if `index` is out of bounds.
+	pub fn get(&self, index: u64) -> Option<u64> {
+		if index >= self.len {
+			return None;
+		}
+		let mut value = self.encrypt(index);
+		while value >= self.len {
+			value = self.encrypt(value);
+		}
+		Some(value)
+	}
+
+	fn encrypt(&self, value: u64) -> u64 {
+		let mask = (1_u64 << self.half_bits) - 1;
+		let (mut left, mut right) = (value >> self.half_bits, value & mask);
+		for key in self.keys {
+			let mixed =
+				u128::from(right ^ key).wrapping_mul(0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835);
+			let round = ((mixed >> 64) as u64) ^ (mixed as u64);
+			let next = left ^ (round & mask);
+			left = right;
+			right = next;
+		}
+		(left << self.half_bits) | right
+	}
+}
+
+impl Iterator for IndexPermutation {
+	type Item = u64;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let value = self.get(self.position)?;
+		self.position += 1;
+		Some(value)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.len - self.position;
+		match usize::try_from(remaining) {
+			Ok(remaining) => (remaining, Some(remaining)),
+			Err(_) => (usize::MAX, None),
+		}
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.position = self.position.saturating_add(n as u64).min(self.len);
+		self.next()
+	}
+}
+
+impl FusedIterator for IndexPermutation {}
+
+#[cfg(test)]
+mod tests {
+	use super::{batched_indices, IndexPermutation};
+	use crate::{Rng, WyRand};
+
+	#[test]
+	fn ensure_shuffle_is_uniform() {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let mut counts = [0_usize; 24];
+		for _ in 0..96_000 {
+			let mut items = [0, 1, 2, 3];
+			rng.shuffle(&mut items);
+			// Every permutation of 4 items gets a unique index in 0..24.
+			let [a, b, c, d] = items;
+			let rank = |x: usize, rest: &[usize]| rest.iter().filter(|&&y| y < x).count();
+			counts[rank(a, &[b, c, d]) * 6 + rank(b, &[c, d]) * 2 + rank(c, &[d])] += 1;
+		}
+		for count in counts {
+			assert!(
+				(3_700..4_300).contains(&count),
+				"{:?} wasn't uniform",
+				counts
+			);
+		}
+
+		// Make sure every batch size gets used, and that items still end up everywhere equally often.
+		for length in [5, 700, 3_000, 20_000, 600_000] {
+			let trials = (2_000_000 / length).max(10);
+			let mut buckets = [0_usize; 5];
+			let mut items: Vec<usize> = (0..length).collect();
+			for _ in 0..trials {
+				rng.shuffle(&mut items);
+				let position = items.iter().position(|&item| item == 0).unwrap();
+				buckets[position * 5 / length] += 1;
+			}
+			let mut sorted = items.clone();
+			sorted.sort_unstable();
+			assert!(sorted.into_iter().eq(0..length));
+			if trials >= 1_000 {
+				let expected = trials as f64 / 5.0;
+				for count in buckets {
+					assert!(
+						(count as f64 - expected).abs() < 5.0 * (expected * 0.8).sqrt(),
+						"{:?} wasn't uniform for {} items",
+						buckets,
+						length
+					);
+				}
+			}
+		}
+		rng.shuffle(&mut [0_u8; 0]);
+		rng.shuffle(&mut [0_u8; 1]);
+	}
+
+	#[test]
+	fn ensure_batched_indices_are_uniform() {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let mut counts = [[[0_usize; 3]; 4]; 5];
+		for _ in 0..60_000 {
+			let [a, b, c] = batched_indices::<_, 3, 8>(&mut rng, 5);
+			counts[a as usize][b as usize][c as usize] += 1;
+		}
+		for count in counts.iter().flatten().flatten() {
+			assert!((850..1_150).contains(count), "{:?} wasn't uniform", counts);
+		}
+	}
+
+	#[test]
+	fn ensure_partial_shuffle_is_uniform() {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let mut counts = [[0_usize; 8]; 3];
+		for _ in 0..80_000 {
+			let mut items = [0, 1, 2, 3, 4, 5, 6, 7];
+			let (chosen, rest) = rng.partial_shuffle(&mut items, 3);
+			assert_eq!((chosen.len(), rest.len()), (3, 5));
+			for (position, &item) in chosen.iter().enumerate() {
+				counts[position][item] += 1;
+			}
+			items.sort_unstable();
+			assert_eq!(items, [0, 1, 2, 3, 4, 5, 6, 7]);
+		}
+		for count in counts.iter().flatten() {
+			assert!(
+				(9_500..10_500).contains(count),
+				"{:?} wasn't uniform",
+				counts
+			);
+		}
+
+		let mut items = [1, 2, 3];
+		let (chosen, rest) = rng.partial_shuffle(&mut items, 10);
+		assert_eq!((chosen.len(), rest.len()), (3, 0));
+		let (chosen, rest) = rng.partial_shuffle::<u8>(&mut [], 3);
+		assert!(chosen.is_empty() && rest.is_empty());
+	}
+
+	#[test]
+	fn ensure_shuffled_iter_is_uniform() {
+		let mut rng = WyRand::new_seed(0x5eed);
+		let items = ['a', 'b', 'c'];
+		let permutations = ["abc", "acb", "bac", "bca", "cab", "cba"];
+		let mut counts = [0_usize; 6];
+		for _ in 0..60_000 {
+			let shuffled: String = rng.shuffled(&items).collect();
+			counts[permutations.iter().position(|p| *p == shuffled).unwrap()] += 1;
+		}
+		for count in counts {
+			assert!(
+				(9_500..10_500).contains(&count),
+				"{:?} wasn't uniform",
+				counts
+			);
+		}
+		assert_eq!(items, ['a', 'b', 'c']);
+
+		let items: Vec<u32> = (0..1000).collect();
+		let mut shuffled: Vec<u32> = rng.shuffled(&items).copied().collect();
+		assert_ne!(shuffled, items);
+		shuffled.sort_unstable();
+		assert_eq!(shuffled, items);
+		assert_eq!(rng.shuffled(&items).len(), 1000);
+		assert_eq!(rng.shuffled::<u8>(&[]).next(), None);
+	}
+
+	#[test]
+	fn ensure_index_permutation_is_a_bijection() {
+		let mut rng = WyRand::new_seed(0x5eed);
+		for len in [0, 1, 2, 3, 4, 5, 17, 1000, 4096, (1 << 20) + 7] {
+			let permutation = IndexPermutation::new(&mut rng, len);
+			let mut seen = vec![false; len as usize];
+			for value in permutation.clone() {
+				assert!(!seen[value as usize], "{} was visited twice", value);
+				seen[value as usize] = true;
+			}
+			assert!(seen.into_iter().all(|seen| seen));
+			assert_eq!(permutation.get(len), None);
+		}
+
+		let huge = IndexPermutation::new(&mut rng, 1 << 40);
+		let mut first: Vec<u64> = huge.clone().take(10_000).collect();
+		assert!(first.iter().all(|&value| value < 1 << 40));
+		// The values should be spread out over the whole range, rather than clustered at the start.
+		assert!(first.iter().filter(|&&value| value >= 1 << 39).count() > 4_000);
+		first.sort_unstable();
+		first.dedup();
+		assert_eq!(first.len(), 10_000);
+
+		let full = IndexPermutation::new(&mut WyRand::new_seed(1), u64::MAX);
+		assert!(full.get(u64::MAX - 1).is_some());
+		assert_eq!(
+			IndexPermutation::new(&mut WyRand::new_seed(1), 1000).collect::<Vec<_>>(),
+			IndexPermutation::new(&mut WyRand::new_seed(1), 1000).collect::<Vec<_>>()
+		);
+	}
+}
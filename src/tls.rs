@@ -16,6 +16,11 @@ impl Rng<8> for TlsWyRand {
 }
 
 impl SeedableRng<8, 8> for TlsWyRand {
+	/// Creates a handle to a new, seeded [`WyRand`], which isn't shared with [`tls_rng`] or any other handle.
+	fn from_seed(seed: [u8; 8]) -> Self {
+		Self(Rc::new(RefCell::new(WyRand::from_seed(seed))))
+	}
+
 	fn reseed(&mut self, seed: [u8; 8]) {
 		self.0.borrow_mut().reseed(seed);
 	}
@@ -40,3 +45,25 @@ impl SeedableRng<8, 8> for TlsWyRand {
 pub fn tls_rng() -> TlsWyRand {
 	WYRAND.with(|tls| TlsWyRand(tls.clone()))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ensure_seeded_handles_are_detached() {
+		let mut shared = tls_rng();
+		let mut expected = shared.0.borrow().clone();
+
+		let mut a = TlsWyRand::seed_from_u64(42);
+		let mut b = TlsWyRand::seed_from_u64(42);
+		let mut reference = WyRand::seed_from_u64(42);
+		// Each seeded handle has its own generator, so neither one moves the other on.
+		assert_eq!(a.rand(), reference.clone().rand());
+		assert_eq!(b.rand(), reference.rand());
+
+		// The thread-local generator is left exactly where it was.
+		assert_eq!(shared.rand(), expected.rand());
+		assert_eq!(tls_rng().rand(), expected.rand());
+	}
+}